@@ -1,21 +1,31 @@
 use crate::{
-    ops::{Div as UnitDiv, Inv, Mul as UnitMul},
-    typenum::{Constant, ToConst, ToTypenum, Typenum},
+    name::{Named, UnitName},
+    ops::{Align, Aligned, Div as UnitDiv, Inv, Mul as UnitMul, Pow},
+    typenum::{frac::Frac, Constant, ToConst, ToTypenum, Typenum},
     Const, Name, Prefix, Root,
 };
 use const_default::ConstDefault;
 use core::{
-    i32,
+    borrow::Borrow,
     marker::PhantomData,
-    ops::{Add, Neg, Sub},
+    ops::{Add, BitOr, Div, Mul, Neg, Sub},
+};
+use typenum::{
+    Bit, Diff, IsLess, Le, NInt, Negate, NonZero, Or, PInt, Prod, Sum, Unsigned, B0, B1, Z0,
 };
-use typenum::{Diff, Negate, Sum};
 
 /// Prefixed unit
 pub struct Pre<P, R>(PhantomData<(P, R)>);
 
+impl<P, R> ConstDefault for Pre<P, R> {
+    const DEFAULT: Self = Self(PhantomData);
+}
+
 pub trait Exponent {
+    /// Exponent itself or its numerator, if it's a fraction
     const EXP: i8;
+    /// Denominator of a fractional exponent, e. g. `2` for `m^½`
+    const DEN: i8 = 1;
 }
 
 impl Exponent for () {
@@ -43,54 +53,316 @@ impl<P, R> BaseUnit for Pre<P, R> {}
 
 impl<R: Root> BaseUnit for R {}
 
-impl<P: Name, R: Name> crate::name::Display for Pre<P, R> {
-    fn display() -> String {
-        format!("{}{}", P::SHORT, R::SHORT)
+impl<P: Name, R: Name> Named for Pre<P, R> {
+    fn unit_name() -> UnitName {
+        UnitName::prefixed::<P, R>()
     }
 }
 
-impl<P: Name, R: Name> crate::name::Debug for Pre<P, R> {
-    fn debug() -> String {
-        format!("{}{}", P::FULL, R::FULL)
+impl<U: Named, E> Named for (U, E) {
+    fn unit_name() -> UnitName {
+        U::unit_name()
     }
 }
 
-impl<U: crate::name::Display, E> crate::name::Display for (U, E) {
-    fn display() -> String {
-        U::display()
+impl<U: Named, const E: i8> Named for Exp<U, E> {
+    fn unit_name() -> UnitName {
+        U::unit_name()
     }
 }
 
-impl<U: crate::name::Debug, E> crate::name::Debug for (U, E) {
-    fn debug() -> String {
-        U::debug()
-    }
+/// Whether the exponent is zero: `B1` for `Const<0>`, `B0` otherwise
+pub trait IsZero {
+    type Output;
 }
 
-impl<U: crate::name::Display, const E: i8> crate::name::Display for Exp<U, E> {
-    fn display() -> String {
-        U::display()
-    }
+impl<const E: i8> IsZero for Const<E>
+where
+    Const<E>: ToTypenum,
+    Typenum<Const<E>>: IsZero,
+{
+    type Output = <Typenum<Const<E>> as IsZero>::Output;
 }
 
-impl<U: crate::name::Debug, const E: i8> crate::name::Debug for Exp<U, E> {
-    fn debug() -> String {
-        U::debug()
-    }
+/// Reduced fractions are never zero
+impl<N, D> IsZero for Frac<N, D> {
+    type Output = B0;
+}
+
+impl IsZero for Z0 {
+    type Output = B1;
+}
+
+impl<U: Unsigned + NonZero> IsZero for PInt<U> {
+    type Output = B0;
+}
+
+impl<U: Unsigned + NonZero> IsZero for NInt<U> {
+    type Output = B0;
+}
+
+type Zero<E> = <E as IsZero>::Output;
+
+/// Sign of an integer exponent: `B1` for negative ones, e. g. `Const<-1>`, `B0` otherwise
+pub trait IsNegative {
+    type Output;
+}
+
+impl<const E: i8> IsNegative for Const<E>
+where
+    Const<E>: ToTypenum,
+    Typenum<Const<E>>: IsLess<Z0>,
+{
+    type Output = Le<Typenum<Const<E>>, Z0>;
+}
+
+pub type Negative<E> = <E as IsNegative>::Output;
+
+/// Root of the product or the quotient of slots `(Ul, El)` & `(Ur, Er)` for `(Zero<El>, Zero<Er>)`:
+/// the one with non-zero exponent, e. g. `km / ms` is `(km, 1 - 0)` & `(ms, 0 - 1)`.
+/// If both exponents are non-zero, roots must be the same
+pub trait CommonRoot<Ul, Ur> {
+    type Root;
+}
+
+impl<Ul, Ur, Zr> CommonRoot<Ul, Ur> for (B1, Zr) {
+    type Root = Ur;
+}
+
+impl<Ul, Ur> CommonRoot<Ul, Ur> for (B0, B1) {
+    type Root = Ul;
+}
+
+impl<U> CommonRoot<U, U> for (B0, B0) {
+    type Root = U;
+}
+
+type CommonRootOf<Ul, El, Ur, Er> = <(Zero<El>, Zero<Er>) as CommonRoot<Ul, Ur>>::Root;
+
+impl<Ul, El: Add<Er> + IsZero, Ur, Er: IsZero> UnitMul<(Ur, Er)> for (Ul, El)
+where
+    (Zero<El>, Zero<Er>): CommonRoot<Ul, Ur>,
+{
+    type Output = (CommonRootOf<Ul, El, Ur, Er>, Sum<El, Er>);
+}
+
+impl<Ul, El: Sub<Er> + IsZero, Ur, Er: IsZero> UnitDiv<(Ur, Er)> for (Ul, El)
+where
+    (Zero<El>, Zero<Er>): CommonRoot<Ul, Ur>,
+{
+    type Output = (CommonRootOf<Ul, El, Ur, Er>, Diff<El, Er>);
+}
+
+/// `T` for `B1` & `F` for `B0`
+pub trait Select<T, F> {
+    type Output;
+}
+
+impl<T, F> Select<T, F> for B1 {
+    type Output = T;
+}
+
+impl<T, F> Select<T, F> for B0 {
+    type Output = F;
+}
+
+/// Strict order of roots of the same kind or of prefixes, which is `B1`, if `Self` is smaller
+/// than `U`, e. g. `milli` & `kilo` or `foot` & `meter`. `()` is no prefix, e. g. `milli` & `()`.
+///
+/// It's implemented by `impl_finer!` for the roots & prefixes of this crate, so a root
+/// of an existing kind, which is defined elsewhere, needs `Finer` impls against each root
+/// of its kind & itself, so that it can be multiplied or divided by them
+pub trait Finer<U> {
+    type Output: Bit;
+}
+
+/// Implement `Finer` for roots or prefixes, which are listed from the smallest one,
+/// e. g. `impl_finer!(milli, (), kilo)`, or for each pair of two lists, where the lhs is smaller
+macro_rules! impl_finer {
+    (@cross [], [$($r:ty),*]) => {};
+    (@cross [$l:ty $(, $ls:ty)*], [$($r:ty),*]) => {
+        $(impl $crate::base_unit::Finer<$r> for $l {
+            type Output = ::typenum::B1;
+        }
+
+        impl $crate::base_unit::Finer<$l> for $r {
+            type Output = ::typenum::B0;
+        })*
+
+        $crate::base_unit::impl_finer!(@cross [$($ls),*], [$($r),*]);
+    };
+    () => {};
+    ($head:ty $(, $tail:ty)*) => {
+        impl $crate::base_unit::Finer<$head> for $head {
+            type Output = ::typenum::B0;
+        }
+
+        $crate::base_unit::impl_finer!(@cross [$head], [$($tail),*]);
+        $crate::base_unit::impl_finer!($($tail),*);
+    };
+}
+
+pub(crate) use impl_finer;
+
+type FinerOf<L, R> = <L as Finer<R>>::Output;
+
+/// Prefix & root of a base unit, where `()` is no prefix
+pub trait Parts {
+    type Prefix;
+    type Root;
+}
+
+impl<P, R> Parts for Pre<P, R> {
+    type Prefix = P;
+    type Root = R;
+}
+
+impl<R: Root> Parts for R {
+    type Prefix = ();
+    type Root = R;
+}
+
+/// Lexicographic order of pairs, where `Self` is `(Finer<Rl, Rr>, Finer<Rr, Rl>)`
+/// of their first elements & `P` is `Finer` of their second ones
+pub trait Lexicographic<P> {
+    type Output: Bit;
+}
+
+impl<P> Lexicographic<P> for (B1, B0) {
+    type Output = B1;
+}
+
+impl<P> Lexicographic<P> for (B0, B1) {
+    type Output = B0;
+}
+
+impl<P: Bit> Lexicographic<P> for (B0, B0) {
+    type Output = P;
+}
+
+/// Strict order of base units, which share a slot: by roots, then by prefixes,
+/// e. g. `mm` < `m` < `km`, but `ft` < `mm`, because `foot` < `meter`
+pub trait FinerUnit<U> {
+    type Output: Bit;
+}
+
+impl<Ul: Parts, Ur: Parts> FinerUnit<Ur> for Ul
+where
+    Ul::Root: Finer<Ur::Root>,
+    Ur::Root: Finer<Ul::Root>,
+    Ul::Prefix: Finer<Ur::Prefix>,
+    (FinerOf<Ul::Root, Ur::Root>, FinerOf<Ur::Root, Ul::Root>):
+        Lexicographic<FinerOf<Ul::Prefix, Ur::Prefix>>,
+{
+    type Output = <(FinerOf<Ul::Root, Ur::Root>, FinerOf<Ur::Root, Ul::Root>) as Lexicographic<
+        FinerOf<Ul::Prefix, Ur::Prefix>,
+    >>::Output;
+}
+
+/// Root of a slot `(Ul, El)` aligned against `(Ur, Er)` for `(Zero<El>, Zero<Er>)`: the finer one
+/// of both, if both exponents are non-zero, e. g. `km * m` is computed as `(km -> m) * m`,
+/// so that the result doesn't depend on the order of operands & integers are only multiplied
+pub trait AlignRoot<Ul, Ur> {
+    type Root;
+}
+
+impl<Ul, Ur: FinerUnit<Ul>> AlignRoot<Ul, Ur> for (B0, B0)
+where
+    <Ur as FinerUnit<Ul>>::Output: Select<Ur, Ul>,
+{
+    type Root = <<Ur as FinerUnit<Ul>>::Output as Select<Ur, Ul>>::Output;
+}
+
+impl<Ul, Ur> AlignRoot<Ul, Ur> for (B0, B1) {
+    type Root = Ul;
+}
+
+impl<Ul, Ur, Zr> AlignRoot<Ul, Ur> for (B1, Zr) {
+    type Root = Ul;
+}
+
+impl<Ul, El: IsZero, Ur, Er: IsZero> Align<(Ur, Er)> for (Ul, El)
+where
+    (Zero<El>, Zero<Er>): AlignRoot<Ul, Ur>,
+{
+    type Output = (<(Zero<El>, Zero<Er>) as AlignRoot<Ul, Ur>>::Root, El);
+}
+
+impl Align for () {
+    type Output = ();
+}
+
+impl<U, E> Align<(U, E)> for () {
+    type Output = ();
+}
+
+impl<U, E> Align<()> for (U, E) {
+    type Output = (U, E);
+}
+
+/// Whether alignment changes the root of a slot `(Ul, El)` against `(Ur, Er)` for
+/// `(Zero<El>, Zero<Er>)`: only if both exponents are non-zero & `Ur` is finer,
+/// e. g. for `km` against `m`, but not for `m` against `km`, `km` against `km` or `km` against `s`
+pub trait RootChange<Ul, Ur> {
+    type Output: Bit;
+}
+
+impl<Ul, Ur: FinerUnit<Ul>> RootChange<Ul, Ur> for (B0, B0) {
+    type Output = <Ur as FinerUnit<Ul>>::Output;
 }
 
-impl<U, El: Add<Er>, Er> UnitMul<(U, Er)> for (U, El) {
-    type Output = (U, Sum<El, Er>);
+impl<Ul, Ur> RootChange<Ul, Ur> for (B0, B1) {
+    type Output = B0;
 }
 
-impl<U, El: Sub<Er>, Er> UnitDiv<(U, Er)> for (U, El) {
-    type Output = (U, Diff<El, Er>);
+impl<Ul, Ur, Zr> RootChange<Ul, Ur> for (B1, Zr) {
+    type Output = B0;
+}
+
+/// Root of a slot for `Zero<E>`: the default one `D`, if the exponent is zero
+pub trait NormalRoot<U, D> {
+    type Root;
+}
+
+impl<U, D> NormalRoot<U, D> for B1 {
+    type Root = D;
+}
+
+impl<U, D> NormalRoot<U, D> for B0 {
+    type Root = U;
+}
+
+/// Slot with its root replaced by the default one `D`, if the exponent is zero,
+/// so that e. g. `km / m` & `s / s` are the same dimensionless unit
+pub trait Normalize<D> {
+    type Output;
+}
+
+pub type Normalized<S, D> = <S as Normalize<D>>::Output;
+
+impl<U, E: IsZero, D> Normalize<D> for (U, E)
+where
+    Zero<E>: NormalRoot<U, D>,
+{
+    type Output = (<Zero<E> as NormalRoot<U, D>>::Root, E);
+}
+
+impl<D> Normalize<D> for () {
+    type Output = (D, Const<0>);
 }
 
 impl<U, E: Neg> Inv for (U, E) {
     type Output = (U, Negate<E>);
 }
 
+impl<U, E: Mul<P>, P> Pow<P> for (U, E) {
+    type Output = (U, Prod<E, P>);
+}
+
+impl<P> Pow<P> for () {
+    type Output = ();
+}
+
 impl UnitMul for () {
     type Output = ();
 }
@@ -186,10 +458,366 @@ where
 }
 
 pub trait ConvertFrom<U, V> {
+    /// Panics, if an integer value doesn't fit into storage type after conversion,
+    /// e. g. `200 km` in `u8` meters
     fn convert_from(value: V) -> V;
+
+    /// Like `convert_from`, but `None` if an integer value doesn't fit into storage type
+    fn checked_convert_from(value: V) -> Option<V>;
+}
+
+/// Linear conversion to the coherent unit of the same kind, e. g. `Pre<kilo, meter>` -> `meter`
+pub trait Factor<V> {
+    /// Coherent unit, e. g. `meter` for `Pre<kilo, meter>`
+    type Coherent;
+
+    /// Conversion factor as a fraction `(numerator, denominator)`
+    fn factor() -> (V, V);
+}
+
+impl<P: Prefix<V>, R: Factor<V>, V: Mul<Output = V>> Factor<V> for Pre<P, R> {
+    type Coherent = R::Coherent;
+
+    fn factor() -> (V, V) {
+        let (num, den) = R::factor();
+        let (prefix_num, prefix_den) = P::FACTOR;
+        (num * prefix_num, den * prefix_den)
+    }
+}
+
+/// Conversion factor of a slot `(U, E)`, i. e. of `U^E`, known at runtime, e. g. for parsing
+pub trait SlotFactor: Exponent {
+    fn slot_factor() -> (f64, f64);
+}
+
+impl SlotFactor for () {
+    fn slot_factor() -> (f64, f64) {
+        (1.0, 1.0)
+    }
+}
+
+impl<U: Factor<f64>, E> SlotFactor for (U, E)
+where
+    Self: Exponent,
+{
+    fn slot_factor() -> (f64, f64) {
+        let (num, den) = U::factor();
+        let exp = f64::from(Self::EXP) / f64::from(Self::DEN);
+        (num.powf(exp), den.powf(exp))
+    }
+}
+
+/// Multiplication by a ratio of two conversion factors
+pub trait Rescale: Sized {
+    /// `self * (from.0 / from.1) / (to.0 / to.1)`
+    fn rescale(self, from: (Self, Self), to: (Self, Self)) -> Self;
+
+    /// Like `rescale`, but `None` on overflow, e. g. for `3000000 km` in `i32` meters
+    fn checked_rescale(self, from: (Self, Self), to: (Self, Self)) -> Option<Self>;
+}
+
+/// Integer factors `from` & `to` cross-reduced, so that e. g. `mm` -> `μm` doesn't overflow
+macro_rules! reduce {
+    ($type:ident, $from:expr, $to:expr) => {{
+        let gcd = |mut a: $type, mut b: $type| {
+            while b != 0 {
+                (a, b) = (b, a % b);
+            }
+            a
+        };
+        let ((nl, dl), (nr, dr)) = ($from, $to);
+        let (gn, gd) = (gcd(nl, nr), gcd(dl, dr));
+        ((nl / gn, dl / gd), (nr / gn, dr / gd))
+    }};
+}
+
+macro_rules! impl_rescale_for_int {
+    ($($type:ident),+) => {
+        $(impl Rescale for $type {
+            fn rescale(self, from: (Self, Self), to: (Self, Self)) -> Self {
+                let ((nl, dl), (nr, dr)) = reduce!($type, from, to);
+                self * (nl * dr) / (dl * nr)
+            }
+
+            fn checked_rescale(self, from: (Self, Self), to: (Self, Self)) -> Option<Self> {
+                let ((nl, dl), (nr, dr)) = reduce!($type, from, to);
+                self.checked_mul(nl.checked_mul(dr)?)?.checked_div(dl.checked_mul(nr)?)
+            }
+        })+
+    };
+}
+
+impl_rescale_for_int!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize);
+
+macro_rules! impl_rescale_for_float {
+    ($($type:ident),+) => {
+        $(impl Rescale for $type {
+            fn rescale(self, (nl, dl): (Self, Self), (nr, dr): (Self, Self)) -> Self {
+                // cancel equal numerators first, e. g. `π` in `°` -> `′`
+                if nl == nr {
+                    self * dr / dl
+                } else {
+                    self * (nl * dr) / (dl * nr)
+                }
+            }
+
+            /// Floats don't overflow, but become infinite
+            fn checked_rescale(self, from: (Self, Self), to: (Self, Self)) -> Option<Self> {
+                Some(self.rescale(from, to))
+            }
+        })+
+    };
+}
+
+impl_rescale_for_float!(f32, f64);
+
+/// Conversion between base units of the same kind, e. g. `Pre<kilo, meter>` -> `Pre<milli, meter>`
+macro_rules! impl_convert_from_for_base_unit {
+    (@float $($type:ident),+) => {
+        $(impl<Ul, Ur> ConvertFrom<Ul, $type> for Ur
+        where
+            Ul: BaseUnit + Factor<$type>,
+            Ur: BaseUnit + Factor<$type, Coherent = Ul::Coherent>,
+        {
+            fn convert_from(value: $type) -> $type {
+                value.rescale(Ul::factor(), Ur::factor())
+            }
+
+            fn checked_convert_from(value: $type) -> Option<$type> {
+                Some(<Self as ConvertFrom<Ul, $type>>::convert_from(value))
+            }
+        })+
+    };
+    ($($type:ident),+) => {
+        $(impl<Ul, Ur> ConvertFrom<Ul, $type> for Ur
+        where
+            Ul: BaseUnit + Factor<$type>,
+            Ur: BaseUnit + Factor<$type, Coherent = Ul::Coherent>,
+        {
+            fn convert_from(value: $type) -> $type {
+                <Self as ConvertFrom<Ul, $type>>::checked_convert_from(value)
+                    .expect("converted value doesn't fit into storage type")
+            }
+
+            fn checked_convert_from(value: $type) -> Option<$type> {
+                value.checked_rescale(Ul::factor(), Ur::factor())
+            }
+        })+
+    };
+    // factors are reduced in `i128` first, so that e. g. `wk` -> `d` is `7` even in `i16`
+    (@via_i128 $($type:ident),+) => {
+        $(impl<Ul, Ur> ConvertFrom<Ul, $type> for Ur
+        where
+            Ul: BaseUnit + Factor<i128>,
+            Ur: BaseUnit + Factor<i128, Coherent = Ul::Coherent>,
+        {
+            fn convert_from(value: $type) -> $type {
+                <Self as ConvertFrom<Ul, $type>>::checked_convert_from(value)
+                    .expect("converted value doesn't fit into storage type")
+            }
+
+            fn checked_convert_from(value: $type) -> Option<$type> {
+                let value = i128::try_from(value).ok()?.checked_rescale(Ul::factor(), Ur::factor())?;
+                $type::try_from(value).ok()
+            }
+        })+
+    };
+}
+
+impl_convert_from_for_base_unit!(@float f32, f64);
+impl_convert_from_for_base_unit!(i128, u128);
+impl_convert_from_for_base_unit!(@via_i128 i8, u8, i16, u16, i32, u32, i64, u64, isize, usize);
+
+/// `(U, E)` is `U^E`, so the conversion is applied `|E|` times
+impl<Ul, Ur, V, const E: i8> ConvertFrom<(Ul, Const<E>), V> for (Ur, Const<E>)
+where
+    Ur: ConvertFrom<Ul, V>,
+    Ul: ConvertFrom<Ur, V>,
+{
+    fn convert_from(value: V) -> V {
+        (0..E.unsigned_abs()).fold(value, |value, _| {
+            if E > 0 {
+                Ur::convert_from(value)
+            } else {
+                Ul::convert_from(value)
+            }
+        })
+    }
+
+    fn checked_convert_from(value: V) -> Option<V> {
+        (0..E.unsigned_abs()).try_fold(value, |value, _| {
+            if E > 0 {
+                Ur::checked_convert_from(value)
+            } else {
+                Ul::checked_convert_from(value)
+            }
+        })
+    }
+}
+
+/// Implement `ConvertFrom` for slots, which are converted as is
+macro_rules! impl_convert_from_as_is {
+    ($(<$($param:ident),*> ($from:ty, $to:ty),)+) => {
+        $(impl<$($param,)* V> ConvertFrom<$from, V> for $to {
+            fn convert_from(value: V) -> V {
+                value
+            }
+
+            fn checked_convert_from(value: V) -> Option<V> {
+                Some(value)
+            }
+        })+
+    };
+}
+
+// fractional exponents can't be converted (yet), but are aligned as is
+impl_convert_from_as_is! {
+    <U, N, D> ((U, Frac<N, D>), (U, Frac<N, D>)),
+    <> ((), ()),
+    <U> ((), (U, Const<0>)),
+    <U> ((U, Const<0>), ()),
+}
+
+/// Conversion of values from slot `S` into `A`, if `Self` is `B1`, none otherwise
+pub trait ConvertIf<S, A, V> {
+    fn convert(value: V) -> V;
 }
 
-// impl<Pl: Prefix<V>, Rl, Pr: Prefix<V>, Rr, V> ConvertFrom<Pre<Pl, Rl>, V> for Pre<Pr, Rr> {
-//     fn from(value: V) -> V {
-//     }
-// }
+impl<S, A, V> ConvertIf<S, A, V> for B0 {
+    fn convert(value: V) -> V {
+        value
+    }
+}
+
+impl<S, A: ConvertFrom<S, V>, V> ConvertIf<S, A, V> for B1 {
+    fn convert(value: V) -> V {
+        A::convert_from(value)
+    }
+}
+
+/// Alignment against `S`, which converts values only where it changes roots,
+/// e. g. for `km` against `m`, but not for `km` against `km` or `s`, so that these need no `ConvertFrom`
+pub trait AlignValue<S, V>: Align<S> {
+    /// Whether any root is changed
+    type Changed: Bit;
+
+    /// From `Self` into `Aligned<Self, S>`
+    fn align_value(value: V) -> V;
+}
+
+type RootChanged<Ul, El, Ur, Er> = <(Zero<El>, Zero<Er>) as RootChange<Ul, Ur>>::Output;
+
+impl<Ul, El: IsZero, Ur, Er: IsZero, V> AlignValue<(Ur, Er), V> for (Ul, El)
+where
+    (Zero<El>, Zero<Er>): AlignRoot<Ul, Ur> + RootChange<Ul, Ur>,
+    RootChanged<Ul, El, Ur, Er>: ConvertIf<Self, Aligned<Self, (Ur, Er)>, V>,
+{
+    type Changed = RootChanged<Ul, El, Ur, Er>;
+
+    fn align_value(value: V) -> V {
+        <Self::Changed as ConvertIf<Self, Aligned<Self, (Ur, Er)>, V>>::convert(value)
+    }
+}
+
+/// Implement `AlignValue` for slots, which don't change under alignment
+macro_rules! impl_align_value_as_is {
+    ($(<$($param:ident),*> ($lhs:ty, $rhs:ty),)+) => {
+        $(impl<$($param,)* V> AlignValue<$rhs, V> for $lhs {
+            type Changed = B0;
+
+            fn align_value(value: V) -> V {
+                value
+            }
+        })+
+    };
+}
+
+impl_align_value_as_is! {
+    <> ((), ()),
+    <U, E> ((), (U, E)),
+    <U, E> ((U, E), ()),
+}
+
+/// Whether any bit of nested pairs `(B, (B, ..., ()))` is set
+pub trait AnyBit {
+    type Output: Bit;
+}
+
+impl AnyBit for () {
+    type Output = B0;
+}
+
+impl<B: BitOr<<R as AnyBit>::Output>, R: AnyBit> AnyBit for (B, R)
+where
+    Or<B, <R as AnyBit>::Output>: Bit,
+{
+    type Output = Or<B, <R as AnyBit>::Output>;
+}
+
+/// Multiplication & division of values of `U` & `S`, where `Self` is `(Changed, Changed)` of
+/// aligning `U` against `S` & `S` against `U`. Values are converted into aligned units first,
+/// if any root is changed, e. g. integer `(3 km) / (1500 m)` is `3000 / 1500`, & simply
+/// forwarded otherwise
+pub trait AlignedOp<U, S, V> {
+    fn mul<L, R>(lhs: L, rhs: R) -> V
+    where
+        L: Mul<R, Output = V> + Borrow<V>,
+        R: Borrow<V>;
+
+    fn div<L, R>(lhs: L, rhs: R) -> V
+    where
+        L: Div<R, Output = V> + Borrow<V>,
+        R: Borrow<V>;
+}
+
+impl<U, S, V> AlignedOp<U, S, V> for (B0, B0) {
+    fn mul<L, R>(lhs: L, rhs: R) -> V
+    where
+        L: Mul<R, Output = V> + Borrow<V>,
+        R: Borrow<V>,
+    {
+        lhs * rhs
+    }
+
+    fn div<L, R>(lhs: L, rhs: R) -> V
+    where
+        L: Div<R, Output = V> + Borrow<V>,
+        R: Borrow<V>,
+    {
+        lhs / rhs
+    }
+}
+
+/// Implement `AlignedOp`, which converts values, for changes of the lhs, the rhs or both.
+/// Values are copied, which is cheap: only primitives can be converted
+macro_rules! impl_aligned_op_with_conversion {
+    ($(<$($param:ident),*> $changes:ty,)+) => {
+        $(impl<U, S, V, $($param),*> AlignedOp<U, S, V> for $changes
+        where
+            U: AlignValue<S, V>,
+            S: AlignValue<U, V>,
+            V: Copy + Mul<Output = V> + Div<Output = V>,
+        {
+            fn mul<L, R>(lhs: L, rhs: R) -> V
+            where
+                L: Mul<R, Output = V> + Borrow<V>,
+                R: Borrow<V>,
+            {
+                U::align_value(*lhs.borrow()) * S::align_value(*rhs.borrow())
+            }
+
+            fn div<L, R>(lhs: L, rhs: R) -> V
+            where
+                L: Div<R, Output = V> + Borrow<V>,
+                R: Borrow<V>,
+            {
+                U::align_value(*lhs.borrow()) / S::align_value(*rhs.borrow())
+            }
+        })+
+    };
+}
+
+impl_aligned_op_with_conversion! {
+    <Br> (B1, Br),
+    <> (B0, B1),
+}
@@ -1,6 +1,15 @@
+pub mod size;
+
 /// Binary prefixes
 pub mod prefix {
-    use crate::prefix::prefixes;
+    use crate::{
+        base_unit::impl_finer,
+        isq::prefix::{
+            atto, centi, deca, deci, exa, femto, giga, hecto, kilo, mega, micro, milli, nano, peta,
+            pico, tera, yocto, yotta, zepto, zetta,
+        },
+        prefix::prefixes,
+    };
 
     #[rustfmt::skip]
     prefixes! {
@@ -13,15 +22,190 @@ pub mod prefix {
         (zebi, "zebi", Zi, "Zi", 1024, 7),
         (yobi, "yobi", Yi, "Yi", 1024, 8),
     }
+
+    impl_finer!(kibi, mebi, gibi, tebi, pebi, exbi, zebi, yobi);
+
+    // e. g. `kB` & `KiB` share a slot, where `1000^n < 1024^n < 1000^(n + 1)`
+    #[rustfmt::skip]
+    impl_finer!(@cross
+        [yocto, zepto, atto, femto, pico, nano, micro, milli, centi, deci,
+         (), deca, hecto, kilo],
+        [kibi, mebi, gibi, tebi, pebi, exbi, zebi, yobi]
+    );
+    impl_finer!(@cross [kibi], [mega, giga, tera, peta, exa, zetta, yotta]);
+    impl_finer!(@cross [mega], [mebi, gibi, tebi, pebi, exbi, zebi, yobi]);
+    impl_finer!(@cross [mebi], [giga, tera, peta, exa, zetta, yotta]);
+    impl_finer!(@cross [giga], [gibi, tebi, pebi, exbi, zebi, yobi]);
+    impl_finer!(@cross [gibi], [tera, peta, exa, zetta, yotta]);
+    impl_finer!(@cross [tera], [tebi, pebi, exbi, zebi, yobi]);
+    impl_finer!(@cross [tebi], [peta, exa, zetta, yotta]);
+    impl_finer!(@cross [peta], [pebi, exbi, zebi, yobi]);
+    impl_finer!(@cross [pebi], [exa, zetta, yotta]);
+    impl_finer!(@cross [exa], [exbi, zebi, yobi]);
+    impl_finer!(@cross [exbi], [zetta, yotta]);
+    impl_finer!(@cross [zetta], [zebi, yobi]);
+    impl_finer!(@cross [zebi], [yotta]);
+    impl_finer!(@cross [yotta], [yobi]);
 }
 
 /// Base units without prefix
 pub mod root {
-    use crate::root::roots_with_alias;
+    pub use crate::information::{bit, Bit, Byte, B};
+}
 
-    #[rustfmt::skip]
-    roots_with_alias! {
-        (Byte, "byte", B, "B"),
-        (Bit,  "bit", bit, "bit"),
+/// Calls `$callback!` with binary & positive metric prefixes `(system, prefix, feature, symbol)`
+/// & roots `(root, symbol)`, which are combined into prefixed units, e. g. `Kibibyte` & `KiB`
+macro_rules! for_prefixed_units {
+    ($callback:ident) => {
+#[rustfmt::skip]
+        $callback! {
+            [
+                (iec_80000, kibi,  "kibi",  Ki),
+                (iec_80000, mebi,  "mebi",  Mi),
+                (iec_80000, gibi,  "gibi",  Gi),
+                (iec_80000, tebi,  "tebi",  Ti),
+                (iec_80000, pebi,  "pebi",  Pi),
+                (iec_80000, exbi,  "exbi",  Ei),
+                (iec_80000, zebi,  "zebi",  Zi),
+                (iec_80000, yobi,  "yobi",  Yi),
+                (isq,       kilo,  "kilo",  k),
+                (isq,       mega,  "mega",  M),
+                (isq,       giga,  "giga",  G),
+                (isq,       tera,  "tera",  T),
+                (isq,       peta,  "peta",  P),
+                (isq,       exa,   "exa",   E),
+                (isq,       zetta, "zetta", Z),
+                (isq,       yotta, "yotta", Y),
+            ]
+            [
+                (Byte, B),
+                (Bit,  bit),
+            ]
+        }
+    };
+}
+
+/// Prefixed unit aliases, e. g. `pub type Kibibyte = InformationUnit<Pre<kibi, Byte>>`
+macro_rules! prefixed_aliases {
+    ([$($prefix:tt),+ $(,)?] $roots:tt) => {
+        $(prefixed_aliases!(@prefix $prefix $roots);)+
+    };
+    (@prefix ($system:ident, $prefix:ident, $feat:literal, $p:ident) [$(($root:ident, $r:ident)),+ $(,)?]) => {
+        paste::paste! {
+            $(#[cfg(feature = $feat)]
+            pub type [<$prefix:camel $root:lower>] = InformationUnit<
+                crate::base_unit::Pre<crate::$system::prefix::$prefix, super::root::$root>,
+            >;)+
+        }
+    };
+}
+
+/// Prefixed unit constants, e. g. `pub const KiB: Kibibyte = Unit::DEFAULT`
+macro_rules! prefixed_consts {
+    ([$($prefix:tt),+ $(,)?] $roots:tt) => {
+        $(prefixed_consts!(@prefix $prefix $roots);)+
+    };
+    (@prefix ($system:ident, $prefix:ident, $feat:literal, $p:ident) [$(($root:ident, $r:ident)),+ $(,)?]) => {
+        paste::paste! {
+            $(#[cfg(feature = $feat)]
+            pub const [<$p $r>]: super::unit::[<$prefix:camel $root:lower>] =
+                const_default::ConstDefault::DEFAULT;)+
+        }
+    };
+}
+
+/// Units of information, which is a dimension of [`crate::isq::Unit`],
+/// so that e. g. `GiB / s` is a bandwidth
+pub mod unit {
+    use super::root::{bit, B};
+    pub use crate::isq::unit::InformationUnit;
+    use crate::isq::unit::Second;
+    use core::ops::Div;
+
+    pub type Byte = InformationUnit<B>;
+    pub type Bit = InformationUnit<bit>;
+
+    /// Bandwidth, other ones are e. g. `Mbit / s` or `GiB / s`
+    pub type BitPerSecond = <Bit as Div<Second>>::Output;
+    pub type BytePerSecond = <Byte as Div<Second>>::Output;
+
+    for_prefixed_units!(prefixed_aliases);
+}
+
+#[allow(non_upper_case_globals)]
+pub mod consts {
+    use super::unit::{Bit, Byte};
+    use const_default::ConstDefault;
+
+    pub const B: Byte = ConstDefault::DEFAULT;
+    pub const bit: Bit = ConstDefault::DEFAULT;
+
+    for_prefixed_units!(prefixed_consts);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        consts::{bit, B},
+        prefix::Ki,
+        root::Byte,
+        unit,
+    };
+    use crate::{
+        base_unit::{ConvertFrom, Pre},
+        isq::{consts::s, prefix::k},
+    };
+
+    #[test]
+    fn convert_binary_prefixes() {
+        assert_eq!(<Byte as ConvertFrom<Pre<Ki, Byte>, i64>>::convert_from(3), 3072);
+        assert_eq!(<Pre<k, Byte> as ConvertFrom<Pre<Ki, Byte>, i64>>::convert_from(125), 128);
+    }
+
+    #[test]
+    #[cfg(all(feature = "kibi", feature = "mebi"))]
+    fn prefixed_aliases() {
+        use super::unit::{Kibibit, Mebibyte};
+        use crate::Quantity;
+
+        assert_eq!(Quantity::<Mebibyte, u64>::new(2).into::<unit::Byte>(), 2_u64 * 1024 * 1024 * B);
+        assert_eq!(Quantity::<Kibibit, u32>::new(1).into::<unit::Bit>(), 1024_u32 * bit);
+    }
+
+    #[test]
+    fn bits_and_bytes() {
+        assert_eq!((10_u64 * B).into::<unit::Bit>(), 80_u64 * bit);
+        assert_eq!((12_i32 * bit).into::<unit::Byte>(), 1_i32 * B);
+        assert_eq!((4_f64 * B).into::<unit::Bit>(), 32_f64 * bit);
+        assert_eq!((10_u64 * B) + (6_u64 * B), 16_u64 * B);
+        assert_eq!((10_u64 * B).to_string(), "10 B");
+        assert_eq!((3_f64 * (bit / s)).to_string(), "3 bit/s");
+    }
+
+    #[test]
+    #[cfg(all(feature = "kilo", feature = "mega", feature = "kibi", feature = "gibi"))]
+    fn decimal_binary_prefixes_and_bandwidth() {
+        use super::{
+            consts::{kB, GiB, KiB, Mbit, MB},
+            unit::{Kibibyte, Kilobyte, Megabit},
+        };
+        use crate::Quantity;
+
+        assert_eq!((1_u64 * KiB).into::<Kilobyte>(), Quantity::new(1));
+        assert_eq!((1024_u64 * kB).into::<Kibibyte>(), 1000_u64 * KiB);
+        assert_eq!((1_f64 * MB).into::<Megabit>(), 8_f64 * Mbit);
+        assert_eq!((2_f64 * KiB).into::<unit::Byte>(), 2048_f64 * B);
+
+        let link = 100_f64 * (Mbit / s);
+        assert_eq!(link * (8_f64 * s), 800_f64 * Mbit);
+        assert_eq!(((1_f64 * GiB) / (2_f64 * s)).to_string(), "0.5 GiB/s");
+        let bandwidth = (1_u64 * MB) / (1_u64 * s);
+        assert_eq!(bandwidth.into::<unit::BitPerSecond>(), 8_000_000_u64 * (bit / s));
+
+        // `kB` is finer than `KiB`, which is finer than `MB`, whatever the order of operands
+        assert_eq!((1_f64 * KiB) * (2_f64 * MB), 1953.125 * (KiB * KiB));
+        assert_eq!((2_f64 * MB) * (1_f64 * KiB), 1953.125 * (KiB * KiB));
+        assert_eq!((2_f64 * KiB) / (1_f64 * kB), 2.048 * (kB / kB));
+        assert_eq!((1_u64 * B) * (1_u64 * bit), 8_u64 * (bit * bit));
     }
 }
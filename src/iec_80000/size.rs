@@ -0,0 +1,224 @@
+//! Human-readable sizes, e. g. `1536 B` is `1.5 KiB` or `1.54 kB`, & parsing of them
+
+use super::{
+    prefix::{exbi, gibi, kibi, mebi, pebi, tebi, yobi, zebi},
+    root::Byte,
+    unit::{self, InformationUnit},
+};
+use crate::{
+    base_unit::ConvertFrom,
+    isq::prefix::{exa, giga, kilo, mega, peta, tera, yotta, zetta},
+    kind,
+    ops::ToF64,
+    util::pad,
+    Name, Prefix, Quantity,
+};
+use core::fmt::{self, Display, Formatter};
+
+/// Symbols & factors of prefixes, e. g. `("Ki", 1024)`
+macro_rules! prefix_table {
+    ($($prefix:ident),+) => {
+        [$((<$prefix as Name>::SHORT, <$prefix as Prefix<u128>>::FACTOR.0)),+]
+    };
+}
+
+const BINARY: [(&str, u128); 8] = prefix_table!(kibi, mebi, gibi, tebi, pebi, exbi, zebi, yobi);
+const DECIMAL: [(&str, u128); 8] = prefix_table!(kilo, mega, giga, tera, peta, exa, zetta, yotta);
+
+/// Prefixes, which are chosen automatically for human-readable sizes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PrefixMode {
+    /// Powers of `1024`, e. g. `KiB` or `GiB`
+    Binary,
+    /// Powers of `1000`, e. g. `kB` or `GB`
+    Decimal,
+}
+
+/// Size in bytes, which is displayed with the largest prefix, which keeps the value `>= 1`
+///
+/// The value is rounded to 3 significant digits without trailing zeros of the fraction, e. g. `1.54 kB`,
+/// unless precision is specified, e. g. `{:.3}`. Sizes below `1 KiB` or `1 kB` are displayed as is.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HumanBytes {
+    bytes: f64,
+    mode: PrefixMode,
+}
+
+impl<R: kind::Information, V: ToF64> Quantity<InformationUnit<R>, V>
+where
+    Byte: ConvertFrom<R, f64>,
+{
+    /// E. g. `(1536_u64 * B).human(PrefixMode::Binary)` is displayed as `1.5 KiB`
+    pub fn human(self, mode: PrefixMode) -> HumanBytes {
+        HumanBytes { bytes: Byte::convert_from(self.value.to_f64()), mode }
+    }
+}
+
+impl HumanBytes {
+    /// Value in the unit with the prefix, which has the factor, e. g. `1.54` for `1536 B` in `kB`
+    fn number(&self, factor: u128, precision: Option<usize>) -> String {
+        let value = self.bytes / factor as f64;
+        match precision {
+            Some(precision) => format!("{value:.precision$}"),
+            None if factor == 1 => value.to_string(),
+            None => {
+                let decimals = match value.abs() {
+                    value if value >= 100.0 => 0,
+                    value if value >= 10.0 => 1,
+                    _ => 2,
+                };
+                let number = format!("{value:.decimals$}");
+                // Only zeros of the fraction are insignificant, e. g. `100` stays as is
+                if number.contains('.') {
+                    number.trim_end_matches('0').trim_end_matches('.').to_string()
+                } else {
+                    number
+                }
+            }
+        }
+    }
+}
+
+impl Display for HumanBytes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (prefixes, step) = match self.mode {
+            PrefixMode::Binary => (&BINARY, 1024.0),
+            PrefixMode::Decimal => (&DECIMAL, 1000.0),
+        };
+        let mut index = prefixes.iter().rposition(|(_, factor)| self.bytes.abs() >= *factor as f64);
+        loop {
+            let (symbol, factor) = index.map_or(("", 1), |index| prefixes[index]);
+            let number = self.number(factor, f.precision());
+            // Rounding may reach the next prefix, e. g. `999.9 kB` is `1 MB`, not `1000 kB`
+            let next = index.map_or(0, |index| index + 1);
+            let rounded = number.parse::<f64>().unwrap_or_default();
+            if next == prefixes.len() || rounded.abs() < step {
+                return pad(f, &format!("{number} {symbol}B"));
+            }
+            index = Some(next);
+        }
+    }
+}
+
+/// Size can't be parsed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseBytesError {
+    /// Missing or malformed number, e. g. `"GiB"` or `"1.2.3 B"`
+    InvalidNumber,
+    /// Not a byte or a bit with a binary or a metric prefix, e. g. `"4 GB/s"`
+    UnknownUnit,
+    /// Size doesn't fit into `u64` bytes
+    Overflow,
+}
+
+impl Display for ParseBytesError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidNumber => write!(f, "invalid number"),
+            Self::UnknownUnit => write!(f, "unknown unit of information"),
+            Self::Overflow => write!(f, "size is out of range"),
+        }
+    }
+}
+
+impl std::error::Error for ParseBytesError {}
+
+/// Parses a size, e. g. `"4 GiB"`, `"100MB"` or `"1.5 kbit"`, into whole bytes, rounding down
+pub fn parse_bytes(s: &str) -> Result<Quantity<unit::Byte, u64>, ParseBytesError> {
+    let s = s.trim();
+    let split = s.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(s.len());
+    let (number, symbol) = (&s[..split], s[split..].trim_start());
+
+    let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+    if whole.is_empty() && fraction.is_empty() || fraction.contains('.') {
+        return Err(ParseBytesError::InvalidNumber);
+    }
+    // `1.5` is `15 / 10`, so that the size is exact
+    let mut mantissa = 0_u128;
+    for digit in whole.bytes().chain(fraction.bytes()) {
+        mantissa = mantissa
+            .checked_mul(10)
+            .and_then(|mantissa| mantissa.checked_add(u128::from(digit - b'0')))
+            .ok_or(ParseBytesError::Overflow)?;
+    }
+    let scale = u32::try_from(fraction.len())
+        .ok()
+        .and_then(|len| 10_u128.checked_pow(len))
+        .ok_or(ParseBytesError::Overflow)?;
+
+    let (prefix, bits) = if let Some(prefix) = symbol.strip_suffix(<Byte as Name>::SHORT) {
+        (prefix, 1)
+    } else if let Some(prefix) = symbol.strip_suffix(<super::root::Bit as Name>::SHORT) {
+        (prefix, 8)
+    } else {
+        return Err(ParseBytesError::UnknownUnit);
+    };
+    let factor = if prefix.is_empty() {
+        1
+    } else {
+        BINARY
+            .iter()
+            .chain(&DECIMAL)
+            .find(|(symbol, _)| *symbol == prefix)
+            .map(|(_, factor)| *factor)
+            .ok_or(ParseBytesError::UnknownUnit)?
+    };
+
+    let bytes = mantissa.checked_mul(factor).ok_or(ParseBytesError::Overflow)? / (scale * bits);
+    u64::try_from(bytes).map(Quantity::new).map_err(|_| ParseBytesError::Overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_bytes, ParseBytesError, PrefixMode};
+    use crate::iec_80000::consts::{bit, B};
+
+    #[test]
+    fn human_readable_sizes() {
+        assert_eq!((1536_u64 * B).human(PrefixMode::Binary).to_string(), "1.5 KiB");
+        assert_eq!((1536_u64 * B).human(PrefixMode::Decimal).to_string(), "1.54 kB");
+        assert_eq!((1023_u64 * B).human(PrefixMode::Binary).to_string(), "1023 B");
+        assert_eq!(((4_u64 << 30) * B).human(PrefixMode::Binary).to_string(), "4 GiB");
+        assert_eq!((123_456_789_u64 * B).human(PrefixMode::Decimal).to_string(), "123 MB");
+        assert_eq!((12_345_f64 * B).human(PrefixMode::Decimal).to_string(), "12.3 kB");
+        assert_eq!((8192_u32 * bit).human(PrefixMode::Binary).to_string(), "1 KiB");
+        assert_eq!(format!("{:.3}", (1536_u64 * B).human(PrefixMode::Binary)), "1.500 KiB");
+        assert_eq!(format!("{:>9}", (1536_u64 * B).human(PrefixMode::Binary)), "  1.5 KiB");
+        assert_eq!(format!("{:*<9.1}", (1536_u64 * B).human(PrefixMode::Decimal)), "1.5 kB***");
+    }
+
+    #[test]
+    fn whole_numbers_keep_zeros() {
+        assert_eq!((10_u64 * 1024 * B).human(PrefixMode::Binary).to_string(), "10 KiB");
+        assert_eq!((100_u64 * 1024 * B).human(PrefixMode::Binary).to_string(), "100 KiB");
+        assert_eq!((1000_u64 * 1024 * B).human(PrefixMode::Binary).to_string(), "1000 KiB");
+        assert_eq!((10_000_u64 * B).human(PrefixMode::Decimal).to_string(), "10 kB");
+        assert_eq!((200_000_u64 * B).human(PrefixMode::Decimal).to_string(), "200 kB");
+        assert_eq!((100_u64 * B).human(PrefixMode::Decimal).to_string(), "100 B");
+        assert_eq!((10_500_u64 * B).human(PrefixMode::Decimal).to_string(), "10.5 kB");
+    }
+
+    #[test]
+    fn rounding_moves_to_next_prefix() {
+        assert_eq!((999_999_u64 * B).human(PrefixMode::Decimal).to_string(), "1 MB");
+        assert_eq!((999_499_u64 * B).human(PrefixMode::Decimal).to_string(), "999 kB");
+        assert_eq!((((1_u64 << 30) - 1) * B).human(PrefixMode::Binary).to_string(), "1 GiB");
+        assert_eq!(format!("{:.1}", (999_990_u64 * B).human(PrefixMode::Decimal)), "1.0 MB");
+        assert_eq!(format!("{:.0}", (1023.7_f64 * B).human(PrefixMode::Binary)), "1 KiB");
+    }
+
+    #[test]
+    fn parse_sizes() {
+        assert_eq!(parse_bytes("4 GiB"), Ok((4_u64 << 30) * B));
+        assert_eq!(parse_bytes("100MB"), Ok(100_000_000_u64 * B));
+        assert_eq!(parse_bytes(" 1.5 KiB "), Ok(1536_u64 * B));
+        assert_eq!(parse_bytes("12 B"), Ok(12_u64 * B));
+        assert_eq!(parse_bytes("1 kbit"), Ok(125_u64 * B));
+        assert_eq!(parse_bytes(".5 kB"), Ok(500_u64 * B));
+        assert_eq!(parse_bytes("GiB"), Err(ParseBytesError::InvalidNumber));
+        assert_eq!(parse_bytes("1.2.3 B"), Err(ParseBytesError::InvalidNumber));
+        assert_eq!(parse_bytes("4 KB"), Err(ParseBytesError::UnknownUnit));
+        assert_eq!(parse_bytes("4 GiB/s"), Err(ParseBytesError::UnknownUnit));
+        assert_eq!(parse_bytes("16 EiB"), Err(ParseBytesError::Overflow));
+    }
+}
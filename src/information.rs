@@ -0,0 +1,29 @@
+//! Roots of information, which are shared by [`crate::isq::Unit`], where `Bit` is coherent,
+//! & [`crate::iec_80000`], which adds binary prefixes
+
+use crate::{
+    base_unit::{impl_finer, Pre},
+    kind,
+    root::{coherent_roots, roots_with_alias, scaled_roots},
+    Root,
+};
+
+#[rustfmt::skip]
+roots_with_alias! {
+    (Byte, "byte", B,   "B"),
+    (Bit,  "bit",  bit, "bit"),
+}
+
+#[rustfmt::skip]
+scaled_roots! {
+    (Byte, 8 / 1, Bit),
+}
+
+coherent_roots!(Bit);
+
+impl kind::Information for Byte {}
+impl kind::Information for Bit {}
+
+impl_finer!(Bit, Byte);
+
+impl<P, R: Root + kind::Information> kind::Information for Pre<P, R> {}
@@ -1,26 +1,42 @@
 use crate::{
-    base_unit::{Exponent, Pre},
-    name::superscript,
-    ops::{Div as UnitDiv, Inv as UnitInv, Mul as UnitMul},
-    util::{impl_binary_op_for_type_array, impl_unary_op_for_type_array, type_array},
+    base_unit::{ConvertFrom, Exponent, Pre, SlotFactor},
+    kind,
+    name::{fraction, superscript, Named, UnitName},
+    notation::{FormatUnit, Formatted, Notation, Style},
+    ops::{
+        Align, Div as UnitDiv, Inv as UnitInv, Inverse, Mul as UnitMul, Pow as UnitPow, Power,
+        Transcendental, Trig,
+    },
+    typenum::frac::{Half, Third},
+    util::{
+        impl_align_value_for_type_array, impl_binary_op_for_type_array,
+        impl_binary_type_op_for_type_array, impl_convert_from_for_type_array,
+        impl_unary_op_for_type_array, impl_unary_op_with_param_for_type_array, type_array,
+    },
+    Const, Quantity,
 };
 use const_default::ConstDefault;
 use core::{
     fmt::{self, Debug, Display, Formatter},
-    i32,
+    iter,
     marker::PhantomData,
     ops::{Div, Mul},
 };
-use std::ops::Neg;
 
 use self::{
     prefix::kilo,
-    root::{ampere, candela, gram, meter, mole, second, Kelvin},
+    root::{ampere, candela, gram, meter, mole, one, radian, second, Kelvin},
 };
+use crate::information::Bit;
+
+pub mod duration;
+pub mod engineering;
+pub mod parse;
+pub mod temperature;
 
 /// Metric prefixes
 pub mod prefix {
-    use crate::prefix::prefixes;
+    use crate::{base_unit::impl_finer, prefix::prefixes};
 
     #[rustfmt::skip]
     prefixes! {
@@ -45,55 +61,128 @@ pub mod prefix {
         (zetta, "zetta", Z,  "Z",  10,  21),
         (yotta, "yotta", Y,  "Y",  10,  24),
     }
+
+    // `()` is no prefix
+    #[rustfmt::skip]
+    impl_finer!(
+        yocto, zepto, atto, femto, pico, nano, micro, milli, centi, deci,
+        (), deca, hecto, kilo, mega, giga, tera, peta, exa, zetta, yotta
+    );
 }
 
 /// Base units without prefix
 pub mod root {
-    use std::ops::Add;
-
     use crate::{
-        base_unit::{ConvertFrom, Pre},
+        base_unit::{impl_finer, Pre},
         kind,
-        root::{roots, roots_with_alias},
+        root::{coherent_roots, pi_scaled_roots, roots, roots_with_alias, scaled_roots},
         Root,
     };
 
     #[rustfmt::skip]
     roots_with_alias! {
-        (meter,      "meter",             m,    "m"),
-        (AstroUnit,  "astronomical unit", AU,   "AU"),
-        (gram,       "gram",              g,    "g"),
-        (second,     "second",            s,    "s"),
-        (ampere,     "Ampere",            A,    "A"),
-        (Kelvin,     "Kelvin",            K,    "K"),
-        (Celsius,    "degree Celsius",    degC, "°C"),
-        (Fahrenheit, "degree Fahrenheit", degF, "°F"),
-        (mole,       "mole",              mol,  "mol"),
-        (candela,    "candela",           cd,   "cd"),
-        (foot,       "foot",              ft,   "ft"),
-        (yard,       "yard",              yd,   "yd"),
-        (degree,     "degree",            deg,  "°"),
-        (radian,     "radian",            rad,  "rad"),
+        (meter,          "meter",             m,      "m"),
+        (AstroUnit,      "astronomical unit", AU,     "AU"),
+        (gram,           "gram",              g,      "g"),
+        (second,         "second",            s,      "s"),
+        (minute,         "minute",            min,    "min"),
+        (hour,           "hour",              h,      "h"),
+        (day,            "day",               d,      "d"),
+        (week,           "week",              wk,     "wk"),
+        (JulianYear,     "Julian year",       yr,     "a"),
+        (ampere,         "Ampere",            A,      "A"),
+        (Kelvin,         "Kelvin",            K,      "K"),
+        (Celsius,        "degree Celsius",    degC,   "°C"),
+        (Fahrenheit,     "degree Fahrenheit", degF,   "°F"),
+        (Rankine,        "degree Rankine",    degR,   "°R"),
+        (mole,           "mole",              mol,    "mol"),
+        (candela,        "candela",           cd,     "cd"),
+        (foot,           "foot",              ft,     "ft"),
+        (yard,           "yard",              yd,     "yd"),
+        (degree,         "degree",            deg,    "°"),
+        (radian,         "radian",            rad,    "rad"),
+        (arcminute,      "arcminute",         arcmin, "′"),
+        (arcsecond,      "arcsecond",         arcsec, "″"),
+        (turn,           "turn",              tr,     "tr"),
+        (percent,        "percent",           pct,    "%"),
+        (PartPerMillion, "part per million",  ppm,    "ppm"),
     }
 
     #[rustfmt::skip]
     roots! {
-        (inch, "inch", r#"""#),
+        (inch,     "inch",     r#"""#),
+        (one,      "one",      "1"),
+        (permille, "permille", "‰"),
     }
 
-    impl<V: Add<Output = V> + From<f64>> ConvertFrom<Celsius, V> for Kelvin {
-        fn convert_from(value: V) -> V {
-            value + V::from(273.15)
-        }
+    #[rustfmt::skip]
+    scaled_roots! {
+        (Celsius,        1 / 1,               Kelvin),
+        (Fahrenheit,     5 / 9,               Kelvin),
+        (Rankine,        5 / 9,               Kelvin),
+        (foot,           381 / 1250,          meter),
+        (yard,           1143 / 1250,         meter),
+        (inch,           127 / 5000,          meter),
+        (AstroUnit,      149_597_870_700 / 1, meter),
+        (minute,         60 / 1,              second),
+        (hour,           3600 / 1,            second),
+        (day,            86_400 / 1,          second),
+        (week,           604_800 / 1,         second),
+        (JulianYear,     31_557_600 / 1,      second),
+        (percent,        1 / 100,             one),
+        (permille,       1 / 1000,            one),
+        (PartPerMillion, 1 / 1_000_000,       one),
+    }
+
+    #[rustfmt::skip]
+    pi_scaled_roots! {
+        (degree,    1 / 180,     radian),
+        (arcminute, 1 / 10_800,  radian),
+        (arcsecond, 1 / 648_000, radian),
+        (turn,      2 / 1,       radian),
     }
 
+    coherent_roots!(meter, gram, second, ampere, Kelvin, mole, candela, radian, one);
+
     impl kind::Length for meter {}
+    impl kind::Length for foot {}
+    impl kind::Length for yard {}
+    impl kind::Length for inch {}
+    impl kind::Length for AstroUnit {}
     impl kind::Mass for gram {}
     impl kind::Time for second {}
+    impl kind::Time for minute {}
+    impl kind::Time for hour {}
+    impl kind::Time for day {}
+    impl kind::Time for week {}
+    impl kind::Time for JulianYear {}
     impl kind::Current for ampere {}
     impl kind::Temperature for Kelvin {}
+    impl kind::Temperature for Celsius {}
+    impl kind::Temperature for Fahrenheit {}
+    impl kind::Temperature for Rankine {}
     impl kind::AmountOfSubstance for mole {}
     impl kind::LuminousIntensity for candela {}
+    impl kind::Angle for radian {}
+    impl kind::Angle for degree {}
+    impl kind::Angle for arcminute {}
+    impl kind::Angle for arcsecond {}
+    impl kind::Angle for turn {}
+    impl kind::Ratio for one {}
+    impl kind::Ratio for percent {}
+    impl kind::Ratio for permille {}
+    impl kind::Ratio for PartPerMillion {}
+
+    // only roots of the same kind share a slot, degrees of equal size are ordered arbitrarily
+    impl_finer!(inch, foot, yard, meter, AstroUnit);
+    impl_finer!(gram);
+    impl_finer!(second, minute, hour, day, week, JulianYear);
+    impl_finer!(ampere);
+    impl_finer!(Rankine, Fahrenheit, Kelvin, Celsius);
+    impl_finer!(mole);
+    impl_finer!(candela);
+    impl_finer!(arcsecond, arcminute, degree, radian, turn);
+    impl_finer!(PartPerMillion, permille, percent, one);
 
     impl<P, R: Root + kind::Length> kind::Length for Pre<P, R> {}
     impl<P, R: Root + kind::Mass> kind::Mass for Pre<P, R> {}
@@ -102,158 +191,809 @@ pub mod root {
     impl<P, R: Root + kind::Temperature> kind::Temperature for Pre<P, R> {}
     impl<P, R: Root + kind::AmountOfSubstance> kind::AmountOfSubstance for Pre<P, R> {}
     impl<P, R: Root + kind::LuminousIntensity> kind::LuminousIntensity for Pre<P, R> {}
+    impl<P, R: Root + kind::Angle> kind::Angle for Pre<P, R> {}
 }
 
 type Kg = Pre<kilo, gram>;
 
-type_array!(Unit<L = meter, M = Kg, Ti = second, I = ampere, Te = Kelvin, N = mole, J = candela>);
-impl_binary_op_for_type_array!(Unit<L, M, Ti, I, Te, N, J>, Mul, UnitMul);
-impl_binary_op_for_type_array!(Unit<L, M, Ti, I, Te, N, J>, Div, UnitDiv);
-impl_unary_op_for_type_array!(Unit<L, M, Ti, I, Te, N, J>, UnitInv, UnitInv);
+type_array!(Unit<L = meter, M = Kg, Ti = second, I = ampere, Te = Kelvin, N = mole, J = candela, An = radian, R = one, Inf = Bit>);
+impl_binary_op_for_type_array!(
+    Unit<L = meter, M = Kg, Ti = second, I = ampere, Te = Kelvin, N = mole, J = candela, An = radian, R = one, Inf = Bit>,
+    Mul, UnitMul);
+impl_binary_op_for_type_array!(
+    Unit<L = meter, M = Kg, Ti = second, I = ampere, Te = Kelvin, N = mole, J = candela, An = radian, R = one, Inf = Bit>,
+    Div, UnitDiv);
+impl_binary_type_op_for_type_array!(Unit<L, M, Ti, I, Te, N, J, An, R, Inf>, Align, Align);
+impl_unary_op_for_type_array!(Unit<L, M, Ti, I, Te, N, J, An, R, Inf>, UnitInv, UnitInv);
+impl_unary_op_with_param_for_type_array!(
+    Unit<L = meter, M = Kg, Ti = second, I = ampere, Te = Kelvin, N = mole, J = candela, An = radian, R = one, Inf = Bit>,
+    UnitPow<E>,
+    UnitPow
+);
+impl_convert_from_for_type_array!(Unit<L, M, Ti, I, Te, N, J, An, R, Inf>);
+impl_align_value_for_type_array!(Unit<L, M, Ti, I, Te, N, J, An, R, Inf>);
 
-impl<L, M, Ti, I, Te, N, J> ConstDefault for Unit<L, M, Ti, I, Te, N, J> {
+impl<L, M, Ti, I, Te, N, J, An, R, Inf> ConstDefault for Unit<L, M, Ti, I, Te, N, J, An, R, Inf> {
     const DEFAULT: Self = Self(PhantomData);
 }
 
-#[derive(Clone, Debug)]
+impl<L, M, Ti, I, Te, N, J, An, R, Inf> Unit<L, M, Ti, I, Te, N, J, An, R, Inf> {
+    /// `m.pow::<3>()` is `m * m * m`
+    #[must_use]
+    pub const fn pow<const E: i8>(self) -> Power<Self, Const<E>>
+    where
+        Self: UnitPow<Const<E>>,
+        Power<Self, Const<E>>: ConstDefault,
+    {
+        Power::<Self, Const<E>>::DEFAULT
+    }
+
+    /// `(m * m).sqrt()` is `m`, `s.sqrt()` is `s^½`
+    #[must_use]
+    pub const fn sqrt(self) -> Power<Self, Half>
+    where
+        Self: UnitPow<Half>,
+        Power<Self, Half>: ConstDefault,
+    {
+        Power::<Self, Half>::DEFAULT
+    }
+
+    /// `(m * m * m).cbrt()` is `m`, `s.cbrt()` is `s^⅓`
+    #[must_use]
+    pub const fn cbrt(self) -> Power<Self, Third>
+    where
+        Self: UnitPow<Third>,
+        Power<Self, Third>: ConstDefault,
+    {
+        Power::<Self, Third>::DEFAULT
+    }
+
+    /// E. g. `(m / (s * s)).format_with(Notation::Ascii)` is displayed as `m/s^2`
+    pub fn format_with(self, style: impl Into<Style>) -> Formatted<Self> {
+        Formatted::new(self, style)
+    }
+
+    /// `s.recip()` is `1 / s`
+    #[must_use]
+    pub const fn recip(self) -> Inverse<Self>
+    where
+        Self: UnitInv,
+        Inverse<Self>: ConstDefault,
+    {
+        Inverse::<Self>::DEFAULT
+    }
+}
+
+impl<L, M, Ti, I, Te, N, J, An, R, Inf> Unit<L, M, Ti, I, Te, N, J, An, R, Inf>
+where
+    L: SlotFactor,
+    M: SlotFactor,
+    Ti: SlotFactor,
+    I: SlotFactor,
+    Te: SlotFactor,
+    N: SlotFactor,
+    J: SlotFactor,
+    An: SlotFactor,
+    R: SlotFactor,
+    Inf: SlotFactor,
+{
+    /// Exponents `(numerator, denominator)` & conversion factors of slots, e. g. for parsing
+    pub(crate) fn slots() -> [((i8, i8), (f64, f64)); 10] {
+        [
+            ((L::EXP, L::DEN), L::slot_factor()),
+            ((M::EXP, M::DEN), M::slot_factor()),
+            ((Ti::EXP, Ti::DEN), Ti::slot_factor()),
+            ((I::EXP, I::DEN), I::slot_factor()),
+            ((Te::EXP, Te::DEN), Te::slot_factor()),
+            ((N::EXP, N::DEN), N::slot_factor()),
+            ((J::EXP, J::DEN), J::slot_factor()),
+            ((An::EXP, An::DEN), An::slot_factor()),
+            ((R::EXP, R::DEN), R::slot_factor()),
+            ((Inf::EXP, Inf::DEN), Inf::slot_factor()),
+        ]
+    }
+}
+
+/// Trigonometric functions are defined for angles only, e. g. `(30_f64 * deg).sin()`
+impl<R: kind::Angle, V: Trig> Quantity<unit::AngleUnit<R>, V>
+where
+    radian: ConvertFrom<R, V>,
+{
+    pub fn sin(self) -> Quantity<unit::Dimensionless, V> {
+        Quantity::new(radian::convert_from(self.value).sin())
+    }
+
+    pub fn cos(self) -> Quantity<unit::Dimensionless, V> {
+        Quantity::new(radian::convert_from(self.value).cos())
+    }
+
+    pub fn tan(self) -> Quantity<unit::Dimensionless, V> {
+        Quantity::new(radian::convert_from(self.value).tan())
+    }
+}
+
+/// Inverse trigonometric functions return angles
+impl<V: Trig> Quantity<unit::Dimensionless, V> {
+    pub fn asin(self) -> Quantity<unit::Radian, V> {
+        Quantity::new(self.value.asin())
+    }
+
+    pub fn acos(self) -> Quantity<unit::Radian, V> {
+        Quantity::new(self.value.acos())
+    }
+
+    pub fn atan(self) -> Quantity<unit::Radian, V> {
+        Quantity::new(self.value.atan())
+    }
+}
+
+impl<U, V: Trig> Quantity<U, V> {
+    /// Angle of the point `(x, y)`, e. g. `y.atan2(x)` for lengths `x` & `y`
+    pub fn atan2(self, x: Self) -> Quantity<unit::Radian, V> {
+        Quantity::new(self.value.atan2(x.value))
+    }
+}
+
+/// Transcendental functions are defined for dimensionless values only, e. g. `(t / tau).exp()`
+impl<V: Transcendental> Quantity<unit::Dimensionless, V> {
+    #[must_use]
+    pub fn exp(self) -> Self {
+        Self::new(self.value.exp())
+    }
+
+    #[must_use]
+    pub fn exp2(self) -> Self {
+        Self::new(self.value.exp2())
+    }
+
+    #[must_use]
+    pub fn ln(self) -> Self {
+        Self::new(self.value.ln())
+    }
+
+    #[must_use]
+    pub fn log2(self) -> Self {
+        Self::new(self.value.log2())
+    }
+
+    #[must_use]
+    pub fn log10(self) -> Self {
+        Self::new(self.value.log10())
+    }
+
+    #[must_use]
+    pub fn sinh(self) -> Self {
+        Self::new(self.value.sinh())
+    }
+
+    #[must_use]
+    pub fn cosh(self) -> Self {
+        Self::new(self.value.cosh())
+    }
+
+    #[must_use]
+    pub fn tanh(self) -> Self {
+        Self::new(self.value.tanh())
+    }
+}
+
+/// Ratio, e. g. `%`, is a multiple of `1`, which has zero exponent, e. g. `50 %` -> `0.5`
+impl<R: kind::Ratio, V> ConvertFrom<(R, Const<1>), V> for (one, Const<0>)
+where
+    one: ConvertFrom<R, V>,
+{
+    fn convert_from(value: V) -> V {
+        one::convert_from(value)
+    }
+
+    fn checked_convert_from(value: V) -> Option<V> {
+        one::checked_convert_from(value)
+    }
+}
+
+/// `0.5` -> `50 %`
+impl<R: kind::Ratio + ConvertFrom<one, V>, V> ConvertFrom<(one, Const<0>), V> for (R, Const<1>) {
+    fn convert_from(value: V) -> V {
+        R::convert_from(value)
+    }
+
+    fn checked_convert_from(value: V) -> Option<V> {
+        R::checked_convert_from(value)
+    }
+}
+
+#[derive(Clone, Copy)]
 struct ExpUnit {
-    pub name: String,
+    pub name: UnitName,
     pub exp: i8,
+    pub den: i8,
 }
 
 impl ExpUnit {
-    fn new(name: String, exp: i8) -> Self {
-        Self { name, exp }
+    fn new(name: UnitName, exp: i8, den: i8) -> Self {
+        Self { name, exp, den }
     }
-}
 
-impl Neg for ExpUnit {
-    type Output = ExpUnit;
+    /// E. g. `⁻¹` or `½`
+    fn superscript(&self) -> String {
+        if self.den == 1 {
+            superscript(self.exp)
+        } else {
+            fraction(self.exp, self.den)
+        }
+    }
 
-    fn neg(self) -> Self::Output {
-        ExpUnit { exp: -self.exp, ..self }
+    /// E. g. `s²`, `s^2`, `second squared` or `\second\squared`, the exponent is negated
+    /// unless `sign`, e. g. in denominators
+    fn render(&self, notation: Notation, sign: bool) -> String {
+        let name = self.name.render(notation);
+        let exp = if sign { i32::from(self.exp) } else { -i32::from(self.exp) };
+        let den = self.den;
+        match (notation, exp, den) {
+            (_, 1, 1) => name,
+            (Notation::Unicode, _, 1) => format!("{name}{}", superscript(exp)),
+            (Notation::Unicode, ..) => format!("{name}^{}", fraction(exp, den)),
+            (Notation::Ascii, _, 1) => format!("{name}^{exp}"),
+            (Notation::Ascii, ..) => format!("{name}^({exp}/{den})"),
+            (Notation::Long, 2, 1) => format!("{name} squared"),
+            (Notation::Long, 3, 1) => format!("{name} cubed"),
+            (Notation::Long, _, 1) => format!("{name} to the power {exp}"),
+            (Notation::Long, ..) => format!("{name} to the power {exp}/{den}"),
+            (Notation::Latex, 2, 1) => format!("{name}\\squared"),
+            (Notation::Latex, 3, 1) => format!("{name}\\cubed"),
+            (Notation::Latex, _, 1) => format!("{name}\\tothe{{{exp}}}"),
+            (Notation::Latex, ..) => format!("{name}\\tothe{{{exp}/{den}}}"),
+        }
     }
 }
 
-impl Display for ExpUnit {
+/// `Slot { root: "meter", prefix: Some("kilo"), exp: 1 }`
+impl Debug for ExpUnit {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.name)?;
-        if self.exp != 1 {
-            write!(f, "{}", superscript(self.exp))?;
+        let (exp, den) = (self.exp, self.den);
+        let exp = if den == 1 { exp.to_string() } else { format!("{exp}/{den}") };
+        f.debug_struct("Slot")
+            .field("root", &self.name.root.1)
+            .field("prefix", &self.name.prefix.map(|(_, full)| full))
+            .field("exp", &format_args!("{exp}"))
+            .finish()
+    }
+}
+
+/// Dimensions of slots of `Unit`, e. g. for the signature `L¹M⁰T⁻¹I⁰Θ⁰N⁰J⁰An⁰R⁰Inf⁰`
+const DIMENSIONS: [&str; 10] = ["L", "M", "T", "I", "Θ", "N", "J", "An", "R", "Inf"];
+
+/// Coherent SI units in slots of `Unit`
+const COHERENT: [UnitName; 10] = [
+    UnitName::root::<meter>(),
+    UnitName::prefixed::<kilo, gram>(),
+    UnitName::root::<second>(),
+    UnitName::root::<ampere>(),
+    UnitName::root::<Kelvin>(),
+    UnitName::root::<mole>(),
+    UnitName::root::<candela>(),
+    UnitName::root::<radian>(),
+    UnitName::root::<one>(),
+    UnitName::root::<Bit>(),
+];
+
+/// Prefixed inverse second as prefixed hertz with the inverse prefix, e. g. `ms⁻¹` -> `kHz`
+fn prefixed_hertz(unit: &ExpUnit) -> Option<ExpUnit> {
+    if unit.name.root != UnitName::root::<second>().root || (unit.exp, unit.den) != (-1, 1) {
+        return None;
+    }
+    let prefixes = engineering::ALL;
+    let exp =
+        prefixes.iter().find(|prefix| Some((prefix.short, prefix.full)) == unit.name.prefix)?.exp;
+    let inverse = prefixes.iter().find(|prefix| prefix.exp == -exp)?;
+    let (short, full, _) =
+        unit::NAMED.iter().find(|(.., exps)| *exps == [0, 0, -1, 0, 0, 0, 0, 0])?;
+    let name = UnitName { prefix: Some((inverse.short, inverse.full)), root: (short, full) };
+    Some(ExpUnit::new(name, 1, 1))
+}
+
+/// Replaces a part of coherent SI decomposition with a power of a derived unit with special name,
+/// if this makes it shorter, e. g. `m²⋅kg/(s³⋅A)` -> `V` or `m⋅kg/(s³⋅A)` -> `V/m`.
+/// Frequencies are also named with prefixes, e. g. `ms⁻¹` -> `kHz`
+fn with_named_unit(units: Vec<ExpUnit>) -> Vec<ExpUnit> {
+    let mut factors = units.iter().filter(|unit| unit.exp != 0);
+    if let (Some(unit), None) = (factors.next(), factors.next()) {
+        if let Some(hertz) = prefixed_hertz(unit) {
+            return vec![hertz];
         }
-        Ok(())
     }
+    let coherent = units
+        .iter()
+        .zip(COHERENT)
+        .all(|(unit, name)| unit.exp == 0 || (unit.den == 1 && unit.name == name));
+    if !coherent {
+        return units;
+    }
+    // Number of factors first, sum of exponents' magnitudes second
+    let len = |units: &[ExpUnit]| {
+        let units = units.iter().filter(|unit| unit.exp != 0);
+        (units.clone().count(), units.map(|unit| unit.exp.unsigned_abs() as usize).sum::<usize>())
+    };
+    let mut shortest = units.clone();
+    for (short, full, exps) in unit::NAMED {
+        let named = UnitName { prefix: None, root: (short, full) };
+        for power in [1, -1, 2, -2, 3, -3] {
+            let rest: Option<Vec<_>> = units
+                .iter()
+                .zip(COHERENT)
+                // ratios & information have no derived units with special names
+                .zip(exps.iter().chain(iter::repeat(&0)))
+                .map(|((unit, name), exp)| {
+                    let exp = unit.exp.checked_sub(power * exp)?;
+                    Some(ExpUnit::new(name, exp, 1))
+                })
+                .collect();
+            let Some(rest) = rest else { continue };
+            // `s⁻¹` is `Hz`, but `s⁻²` isn't `Hz²`
+            if power == 1 && rest.iter().all(|unit| unit.exp == 0) {
+                return vec![ExpUnit::new(named, 1, 1)];
+            }
+            let candidate: Vec<_> =
+                [ExpUnit::new(named, power, 1)].into_iter().chain(rest).collect();
+            if len(&candidate) < len(&shortest) {
+                shortest = candidate;
+            }
+        }
+    }
+    shortest
 }
 
-fn fmt_product_of_units(f: &mut Formatter<'_>, units: &[ExpUnit], sign: bool) -> fmt::Result {
-    write!(f, "{}", if sign { units[0].clone() } else { -units[0].clone() })?;
-    for unit in &units[1..] {
-        write!(f, "⋅{}", if sign { unit.clone() } else { -unit.clone() })?;
+fn fmt_product_of_units(
+    f: &mut Formatter<'_>,
+    units: &[ExpUnit],
+    sign: bool,
+    notation: Notation,
+) -> fmt::Result {
+    let separator = if notation == Notation::Ascii { "*" } else { "⋅" };
+    for (i, unit) in units.iter().enumerate() {
+        write!(f, "{}{}", if i == 0 { "" } else { separator }, unit.render(notation, sign))?;
     }
     Ok(())
 }
 
-macro_rules! impl_trait_for_unit {
-    ($unit:ident<$($base_unit:ident),+>, $trait:ident, $fun:ident) => {
-        impl<$($base_unit: crate::name::$trait + Exponent),+> $trait for $unit<$($base_unit),+> {
+/// `m⋅kg/s²` or `m*kg/s^2`, where denominators of several units are parenthesized,
+/// `meter kilogram per second squared` or `\metre\kilo\gram\per\second\squared`
+fn fmt_units(f: &mut Formatter<'_>, units: Vec<ExpUnit>, notation: Notation) -> fmt::Result {
+    let (numerator, denominator): (Vec<_>, Vec<_>) =
+        units.into_iter().filter(|unit| unit.exp != 0).partition(|unit| unit.exp > 0);
+    let render = |units: &[ExpUnit], sign, separator| {
+        units.iter().map(|unit| unit.render(notation, sign)).collect::<Vec<_>>().join(separator)
+    };
+    match notation {
+        Notation::Long => match (numerator.is_empty(), denominator.is_empty()) {
+            (_, true) => write!(f, "{}", render(&numerator, true, " ")),
+            (true, false) => write!(f, "per {}", render(&denominator, false, " ")),
+            (false, false) => {
+                let (numerator, denominator) =
+                    (render(&numerator, true, " "), render(&denominator, false, " "));
+                write!(f, "{numerator} per {denominator}")
+            }
+        },
+        // `\per` applies to a single unit
+        Notation::Latex => {
+            write!(f, "{}", render(&numerator, true, ""))?;
+            denominator
+                .iter()
+                .try_for_each(|unit| write!(f, "\\per{}", unit.render(notation, false)))
+        }
+        Notation::Unicode | Notation::Ascii => match (numerator.len(), denominator.len()) {
+            (0, 0) => Ok(()),
+            (_, 0) => fmt_product_of_units(f, &numerator, true, notation),
+            (0, _) => fmt_product_of_units(f, &denominator, true, notation),
+            (1, 1) => {
+                fmt_product_of_units(f, &numerator, true, notation)?;
+                write!(f, "/")?;
+                fmt_product_of_units(f, &denominator, false, notation)
+            }
+            (1, _) => {
+                fmt_product_of_units(f, &numerator, true, notation)?;
+                write!(f, "/(")?;
+                fmt_product_of_units(f, &denominator, false, notation)?;
+                write!(f, ")")
+            }
+            (_, 1) => {
+                write!(f, "(")?;
+                fmt_product_of_units(f, &numerator, true, notation)?;
+                write!(f, ")/")?;
+                fmt_product_of_units(f, &denominator, false, notation)
+            }
+            _ => {
+                write!(f, "(")?;
+                fmt_product_of_units(f, &numerator, true, notation)?;
+                write!(f, ")/(")?;
+                fmt_product_of_units(f, &denominator, false, notation)?;
+                write!(f, ")")
+            }
+        },
+    }
+}
+
+macro_rules! impl_format_unit {
+    ($unit:ident<$($base_unit:ident),+>) => {
+        impl<$($base_unit: Named + Exponent),+> $unit<$($base_unit),+> {
+            /// Base units, which are replaced with a derived unit with special name if `named`
+            fn exp_units(named: bool) -> Vec<ExpUnit> {
+                let units = vec![$(ExpUnit::new($base_unit::unit_name(), $base_unit::EXP, $base_unit::DEN)),+];
+                if named { with_named_unit(units) } else { units }
+            }
+        }
+
+        impl<$($base_unit: Named + Exponent),+> FormatUnit for $unit<$($base_unit),+> {
+            fn fmt_unit(f: &mut Formatter<'_>, notation: Notation, named: bool) -> fmt::Result {
+                fmt_units(f, Self::exp_units(named), notation)
+            }
+        }
+
+        // `{}` prints derived units with special names, e. g. `W`, `{:#}` prints SI base units only
+        impl<$($base_unit: Named + Exponent),+> Display for $unit<$($base_unit),+> {
             fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-                let (numerator, denominator): (Vec<_>, Vec<_>) = [
-                    $(ExpUnit::new($base_unit::$fun(), $base_unit::EXP)),+
-                ].into_iter()
-                    .filter(|unit| unit.exp != 0)
-                    .partition(|unit| unit.exp > 0);
-
-                match (numerator.len(), denominator.len()) {
-                    (0, 0) => Ok(()),
-                    (_, 0) => fmt_product_of_units(f, &numerator, true),
-                    (0, _) => fmt_product_of_units(f, &denominator, true),
-                    (1, 1) => {
-                        fmt_product_of_units(f, &numerator, true)?;
-                        write!(f, "/")?;
-                        fmt_product_of_units(f, &denominator, false)
+                Self::fmt_unit(f, Notation::Unicode, !f.alternate())
+            }
+        }
 
-                    }
-                    (1, _) => {
-                        fmt_product_of_units(f, &numerator, true)?;
-                        write!(f, "/(")?;
-                        fmt_product_of_units(f, &denominator, false)?;
-                        write!(f, ")")
-                    }
-                    (_, 1) => {
-                        write!(f, "(")?;
-                        fmt_product_of_units(f, &numerator, true)?;
-                        write!(f, ")/")?;
-                        fmt_product_of_units(f, &denominator, false)
-                    }
-                    _      => {
-                        write!(f, "(")?;
-                        fmt_product_of_units(f, &numerator, true)?;
-                        write!(f, ")/(")?;
-                        fmt_product_of_units(f, &denominator, false)?;
-                        write!(f, ")")
+        /// Slots, which aren't dimensionless coherent defaults, & the dimension signature,
+        /// so that e. g. `km` & `m` differ in failed assertions
+        impl<$($base_unit: Named + Exponent),+> Debug for $unit<$($base_unit),+> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                let units = Self::exp_units(false);
+                let mut unit = f.debug_struct(stringify!($unit));
+                for ((slot, dimension), coherent) in units.iter().zip(DIMENSIONS).zip(COHERENT) {
+                    if slot.exp != 0 || (slot.name != coherent && slot.name.root != ("", "")) {
+                        unit.field(dimension, slot);
                     }
                 }
+                let signature: String = units
+                    .iter()
+                    .zip(DIMENSIONS)
+                    .map(|(slot, dimension)| format!("{dimension}{}", slot.superscript()))
+                    .collect();
+                unit.field("dimension", &format_args!("{signature}")).finish()
             }
         }
     };
 }
 
-impl_trait_for_unit!(Unit<L, M, Ti, I, Te, N, J>, Display, display);
-impl_trait_for_unit!(Unit<L, M, Ti, I, Te, N, J>, Debug, debug);
+impl_format_unit!(Unit<L, M, Ti, I, Te, N, J, An, R, Inf>);
+
+/// Calls `$callback!` with metric prefixes `(prefix, feature, symbol, inverse prefix)`
+/// & roots `(root, symbol, unit)`, which are combined into prefixed units, e. g. `Kilometer` & `km`
+macro_rules! for_prefixed_units {
+    ($callback:ident) => {
+#[rustfmt::skip]
+        $callback! {
+            [
+                (yocto, "yocto", y,  yotta),
+                (zepto, "zepto", z,  zetta),
+                (atto,  "atto",  a,  exa),
+                (femto, "femto", f,  peta),
+                (pico,  "pico",  p,  tera),
+                (nano,  "nano",  n,  giga),
+                (micro, "micro", u,  mega),
+                (milli, "milli", m,  kilo),
+                (centi, "centi", c,  hecto),
+                (deci,  "deci",  d,  deca),
+                (deca,  "deca",  da, deci),
+                (hecto, "hecto", h,  centi),
+                (kilo,  "kilo",  k,  milli),
+                (mega,  "mega",  M,  micro),
+                (giga,  "giga",  G,  nano),
+                (tera,  "tera",  T,  pico),
+                (peta,  "peta",  P,  femto),
+                (exa,   "exa",   E,  atto),
+                (zetta, "zetta", Z,  zepto),
+                (yotta, "yotta", Y,  yocto),
+            ]
+            [
+                (meter,   m,   LengthUnit),
+                (gram,    g,   MassUnit),
+                (second,  s,   TimeUnit),
+                (ampere,  A,   CurrentUnit),
+                (Kelvin,  K,   TemperatureUnit),
+                (mole,    mol, AmountUnit),
+                (candela, cd,  LuminousIntensityUnit),
+                (radian,  rad, AngleUnit),
+            ]
+        }
+    };
+}
+
+/// Prefixed unit aliases, e. g. `pub type Kilometer = LengthUnit<Pre<kilo, meter>>`
+macro_rules! prefixed_aliases {
+    ([$($prefix:tt),+ $(,)?] $roots:tt) => {
+        $(prefixed_aliases!(@prefix $prefix $roots);)+
+    };
+    (@prefix ($prefix:ident, $feat:literal, $p:ident, $inverse:ident) [$($root:tt),+ $(,)?]) => {
+        $(prefixed_aliases!(@unit $prefix, $feat, $root);)+
+
+        // kHz is 1/ms, which is displayed as kHz
+        paste::paste! {
+            #[cfg(feature = $feat)]
+            pub type [<$prefix:camel hertz>] =
+                FrequencyUnit<crate::base_unit::Pre<crate::isq::prefix::$inverse, crate::isq::root::second>>;
+        }
+    };
+    // `Kilogram` is the coherent unit of mass, so it's defined along with other SI base units
+    (@unit kilo, $feat:literal, (gram, $r:ident, $unit:ident)) => {};
+    (@unit $prefix:ident, $feat:literal, ($root:ident, $r:ident, $unit:ident)) => {
+        paste::paste! {
+            #[cfg(feature = $feat)]
+            pub type [<$prefix:camel $root:lower>] =
+                $unit<crate::base_unit::Pre<crate::isq::prefix::$prefix, crate::isq::root::$root>>;
+        }
+    };
+}
+
+/// Prefixed unit constants, e. g. `pub const km: Kilometer = Unit::DEFAULT`
+macro_rules! prefixed_consts {
+    ([$($prefix:tt),+ $(,)?] $roots:tt) => {
+        $(prefixed_consts!(@prefix $prefix $roots);)+
+    };
+    (@prefix ($prefix:ident, $feat:literal, $p:ident, $inverse:ident) [$($root:tt),+ $(,)?]) => {
+        $(prefixed_consts!(@unit $prefix, $feat, $p, $root);)+
+
+        paste::paste! {
+            #[cfg(feature = $feat)]
+            pub const [<$p Hz>]: [<$prefix:camel hertz>] = Unit::DEFAULT;
+        }
+    };
+    (@unit kilo, $feat:literal, $p:ident, (gram, $r:ident, $unit:ident)) => {};
+    // `as` is a keyword
+    (@unit atto, $feat:literal, $p:ident, (second, $r:ident, $unit:ident)) => {
+        #[cfg(feature = $feat)]
+        pub const r#as: Attosecond = Unit::DEFAULT;
+    };
+    (@unit $prefix:ident, $feat:literal, $p:ident, ($root:ident, $r:ident, $unit:ident)) => {
+        paste::paste! {
+            #[cfg(feature = $feat)]
+            pub const [<$p $r>]: [<$prefix:camel $root:lower>] = Unit::DEFAULT;
+        }
+    };
+}
 
 pub mod unit {
     use super::{
-        prefix::{k, kilo},
-        root::{cd, g, gram, m, meter, mol, s, second, A, K},
+        prefix::k,
+        root::{
+            arcmin, arcsec, cd, d, deg, degC, degF, degR, ft, g, h, inch, m, min, mol, one, pct,
+            permille, ppm, rad, s, tr, wk, yd, yr, A, AU, K,
+        },
         Unit,
     };
-    use crate::{
-        base_unit::{Exp, Pre},
-        typenum::Const,
-    };
+    use crate::{base_unit::Pre, typenum::Const};
+
+    /// Units of a single base quantity with an arbitrary base unit, e. g. `LengthUnit<Pre<k, m>>`
+    pub type LengthUnit<U> = Unit<(U, Const<1>)>;
+    pub type MassUnit<U> = Unit<(m, Const<0>), (U, Const<1>)>;
+    pub type TimeUnit<U> = Unit<(m, Const<0>), (Pre<k, g>, Const<0>), (U, Const<1>)>;
+    pub type CurrentUnit<U> =
+        Unit<(m, Const<0>), (Pre<k, g>, Const<0>), (s, Const<0>), (U, Const<1>)>;
+    pub type TemperatureUnit<U> =
+        Unit<(m, Const<0>), (Pre<k, g>, Const<0>), (s, Const<0>), (A, Const<0>), (U, Const<1>)>;
+    pub type AmountUnit<U> = Unit<
+        (m, Const<0>),
+        (Pre<k, g>, Const<0>),
+        (s, Const<0>),
+        (A, Const<0>),
+        (K, Const<0>),
+        (U, Const<1>),
+    >;
+    pub type LuminousIntensityUnit<U> = Unit<
+        (m, Const<0>),
+        (Pre<k, g>, Const<0>),
+        (s, Const<0>),
+        (A, Const<0>),
+        (K, Const<0>),
+        (mol, Const<0>),
+        (U, Const<1>),
+    >;
+    pub type AngleUnit<U> = Unit<
+        (m, Const<0>),
+        (Pre<k, g>, Const<0>),
+        (s, Const<0>),
+        (A, Const<0>),
+        (K, Const<0>),
+        (mol, Const<0>),
+        (cd, Const<0>),
+        (U, Const<1>),
+    >;
+    pub type RatioUnit<U> = Unit<
+        (m, Const<0>),
+        (Pre<k, g>, Const<0>),
+        (s, Const<0>),
+        (A, Const<0>),
+        (K, Const<0>),
+        (mol, Const<0>),
+        (cd, Const<0>),
+        (rad, Const<0>),
+        (U, Const<1>),
+    >;
+    /// Information, e. g. `InformationUnit<B>` is byte, see [`crate::iec_80000`]
+    pub type InformationUnit<U> = Unit<
+        (m, Const<0>),
+        (Pre<k, g>, Const<0>),
+        (s, Const<0>),
+        (A, Const<0>),
+        (K, Const<0>),
+        (mol, Const<0>),
+        (cd, Const<0>),
+        (rad, Const<0>),
+        (one, Const<0>),
+        (U, Const<1>),
+    >;
+    /// Inverse of a time unit, e. g. `FrequencyUnit<Pre<m, s>>` is kilohertz
+    pub type FrequencyUnit<U> = Unit<(m, Const<0>), (Pre<k, g>, Const<0>), (U, Const<-1>)>;
+
+    pub type Celsius = TemperatureUnit<degC>;
+    pub type Fahrenheit = TemperatureUnit<degF>;
+    pub type Rankine = TemperatureUnit<degR>;
+
+    pub type Foot = LengthUnit<ft>;
+    pub type Yard = LengthUnit<yd>;
+    pub type Inch = LengthUnit<inch>;
+    pub type AstronomicalUnit = LengthUnit<AU>;
+
+    pub type Degree = AngleUnit<deg>;
+    pub type Arcminute = AngleUnit<arcmin>;
+    pub type Arcsecond = AngleUnit<arcsec>;
+    pub type Turn = AngleUnit<tr>;
+
+    pub type Minute = TimeUnit<min>;
+    pub type Hour = TimeUnit<h>;
+    pub type Day = TimeUnit<d>;
+    pub type Week = TimeUnit<wk>;
+    /// `365.25 d`, e. g. in the definition of light-year
+    pub type JulianYear = TimeUnit<yr>;
+
+    /// Ratios, which are [`Dimensionless`] after conversion, e. g. `50 %` is `0.5`
+    pub type Percent = RatioUnit<pct>;
+    pub type Permille = RatioUnit<permille>;
+    pub type PartsPerMillion = RatioUnit<ppm>;
+
+    for_prefixed_units!(prefixed_aliases);
 
     macro_rules! unit_aliases {
-        ($(($m:literal, $kg:literal, $s:literal, $A:literal, $K:literal, $mol:literal, $cd:literal) -> $alias:ident,)+) => {
-            $(pub type $alias =
-                Unit<(m, Const<$m>), (Pre<k, g>, Const<$kg>), (s, Const<$s>), (A, Const<$A>), (K, Const<$K>), (mol, Const<$mol>), (cd, Const<$cd>)>;)+
+        ($(($m:literal, $kg:literal, $s:literal, $A:literal, $K:literal, $mol:literal, $cd:literal, $rad:literal) -> $alias:ident,)+) => {
+            $(pub type $alias = Unit<
+                (m, Const<$m>),
+                (Pre<k, g>, Const<$kg>),
+                (s, Const<$s>),
+                (A, Const<$A>),
+                (K, Const<$K>),
+                (mol, Const<$mol>),
+                (cd, Const<$cd>),
+                (rad, Const<$rad>),
+                (one, Const<0>),
+            >;)+
+        };
+    }
+
+    /// Aliases of derived units with special names, which `Display` prints by their symbols,
+    /// e. g. `N` instead of `m⋅kg/s²`
+    macro_rules! named_unit_aliases {
+        ($(($m:literal, $kg:literal, $s:literal, $A:literal, $K:literal, $mol:literal, $cd:literal, $rad:literal) -> $alias:ident, $symbol:literal, $name:literal,)+) => {
+            unit_aliases! {
+                $(($m, $kg, $s, $A, $K, $mol, $cd, $rad) -> $alias,)+
+            }
+
+            /// Symbols, full names & exponents of `m, kg, s, A, K, mol, cd, rad` of derived units with special names
+            pub(crate) const NAMED: &[(&str, &str, [i8; 8])] = &[$(($symbol, $name, [$m, $kg, $s, $A, $K, $mol, $cd, $rad])),+];
         };
     }
 
+    #[rustfmt::skip]
+    named_unit_aliases! {
+        // m kg  s  A  K mol cd rad
+        (0, 0,-1, 0, 0, 0, 0, 0) -> Hertz,     "Hz",  "hertz",
+        (1, 1,-2, 0, 0, 0, 0, 0) -> Newton,    "N",   "newton",
+        (-1,1,-2, 0, 0, 0, 0, 0) -> Pascal,    "Pa",  "pascal",
+        (2, 1,-2, 0, 0, 0, 0, 0) -> Joule,     "J",   "joule",
+        (2, 1,-3, 0, 0, 0, 0, 0) -> Watt,      "W",   "watt",
+        (0, 0, 1, 1, 0, 0, 0, 0) -> Coulomb,   "C",   "coulomb",
+        (2, 1,-3,-1, 0, 0, 0, 0) -> Volt,      "V",   "volt",
+        (-2,-1,4, 2, 0, 0, 0, 0) -> Farad,     "F",   "farad",
+        (2, 1,-3,-2, 0, 0, 0, 0) -> Ohm,       "Ω",   "ohm",
+        (-2,-1,3, 2, 0, 0, 0, 0) -> Siemens,   "S",   "siemens",
+        (2, 1,-2,-1, 0, 0, 0, 0) -> Weber,     "Wb",  "weber",
+        (0, 1,-2,-1, 0, 0, 0, 0) -> Tesla,     "T",   "tesla",
+        (2, 1,-2,-2, 0, 0, 0, 0) -> Henry,     "H",   "henry",
+        (0, 0, 0, 0, 0, 0, 0, 2) -> Steradian, "sr",  "steradian",
+        (0, 0, 0, 0, 0, 0, 1, 2) -> Lumen,     "lm",  "lumen",
+        (-2,0, 0, 0, 0, 0, 1, 2) -> Lux,       "lx",  "lux",
+        (0, 0,-1, 0, 0, 1, 0, 0) -> Katal,     "kat", "katal",
+    }
+
+    #[rustfmt::skip]
     unit_aliases! {
-        (0, 0, 0, 0, 0, 0, 0) -> Dimensionless,
-        // (1, 0, 0, 0, 0, 0, 0) -> Meter,
-        (0, 1, 0, 0, 0, 0, 0) -> Kilogram,
-        // (0, 0, 1, 0, 0, 0, 0) -> Second,
-        (0, 0, 0, 1, 0, 0, 0) -> Ampere,
-        (0, 0, 0, 0, 1, 0, 0) -> Kelvin,
-        (0, 0, 0, 0, 0, 1, 0) -> Mole,
-        (0, 0, 0, 0, 0, 0, 1) -> Candela,
-        (1, 0,-1, 0, 0, 0, 0) -> MeterPerSecond,
-        (2, 0, 0, 0, 0, 0, 0) -> MeterSquared,
-    }
-    pub type Meter = Unit<(meter, Const<1>)>;
-    // pub type Kilometer = Unit<(Pre<kilo, meter>, Const<1>)>;
-    // pub type MeterSquared = Unit<(meter, Const<2>)>;
-    pub type Second = Unit<(), (), (second, Const<1>)>;
-    // pub type Kilogram = Unit<(), (Pre<kilo, gram>, Const<1>)>;
+        // m kg  s  A  K mol cd rad
+        (0, 0, 0, 0, 0, 0, 0, 0) -> Dimensionless,
+        (1, 0, 0, 0, 0, 0, 0, 0) -> Meter,
+        (0, 1, 0, 0, 0, 0, 0, 0) -> Kilogram,
+        (0, 0, 1, 0, 0, 0, 0, 0) -> Second,
+        (0, 0, 0, 1, 0, 0, 0, 0) -> Ampere,
+        (0, 0, 0, 0, 1, 0, 0, 0) -> Kelvin,
+        (0, 0, 0, 0, 0, 1, 0, 0) -> Mole,
+        (0, 0, 0, 0, 0, 0, 1, 0) -> Candela,
+        (0, 0, 0, 0, 0, 0, 0, 1) -> Radian,
+
+        // Derived units with special names, which are indistinguishable from others by dimension
+        (0, 0,-1, 0, 0, 0, 0, 0) -> Becquerel,
+        (2, 0,-2, 0, 0, 0, 0, 0) -> Gray,
+        (2, 0,-2, 0, 0, 0, 0, 0) -> Sievert,
+
+        // Compound units
+        (1, 0,-1, 0, 0, 0, 0, 0) -> MeterPerSecond,
+        (1, 0,-2, 0, 0, 0, 0, 0) -> MeterPerSecondSquared,
+        (2, 0, 0, 0, 0, 0, 0, 0) -> MeterSquared,
+        (3, 0, 0, 0, 0, 0, 0, 0) -> MeterCubed,
+        (2, 1,-2, 0, 0, 0, 0, 0) -> NewtonMeter,
+        (0, 1,-3, 0, 0, 0, 0, 0) -> WattPerMeterSquared,
+        (2, 0,-2, 0,-1, 0, 0, 0) -> JoulePerKilogramKelvin,
+        (0, 0,-1, 0, 0, 0, 0, 1) -> RadianPerSecond,
+    }
 }
 
 #[allow(non_upper_case_globals)]
 pub mod consts {
-    use super::{
-        unit::{Ampere, Candela, Kelvin, Kilogram, Meter, MeterSquared, Mole, Second},
-        Unit,
-    };
+    use super::{unit::*, Unit};
     use const_default::ConstDefault;
 
+    for_prefixed_units!(prefixed_consts);
+
     pub const m: Meter = Unit::DEFAULT;
     pub const m2: MeterSquared = Unit::DEFAULT;
+    pub const m3: MeterCubed = Unit::DEFAULT;
     pub const kg: Kilogram = Unit::DEFAULT;
     pub const s: Second = Unit::DEFAULT;
     pub const A: Ampere = Unit::DEFAULT;
     pub const K: Kelvin = Unit::DEFAULT;
+    pub const degC: Celsius = Unit::DEFAULT;
+    pub const degF: Fahrenheit = Unit::DEFAULT;
+    pub const degR: Rankine = Unit::DEFAULT;
+    pub const ft: Foot = Unit::DEFAULT;
+    pub const yd: Yard = Unit::DEFAULT;
+    pub const inch: Inch = Unit::DEFAULT;
+    pub const AU: AstronomicalUnit = Unit::DEFAULT;
+    pub const deg: Degree = Unit::DEFAULT;
+    pub const arcmin: Arcminute = Unit::DEFAULT;
+    pub const arcsec: Arcsecond = Unit::DEFAULT;
+    pub const tr: Turn = Unit::DEFAULT;
+    pub const min: Minute = Unit::DEFAULT;
+    pub const h: Hour = Unit::DEFAULT;
+    pub const d: Day = Unit::DEFAULT;
+    pub const wk: Week = Unit::DEFAULT;
+    pub const yr: JulianYear = Unit::DEFAULT;
+    pub const pct: Percent = Unit::DEFAULT;
+    pub const permille: Permille = Unit::DEFAULT;
+    pub const ppm: PartsPerMillion = Unit::DEFAULT;
     pub const mol: Mole = Unit::DEFAULT;
     pub const cd: Candela = Unit::DEFAULT;
+
+    pub const rad: Radian = Unit::DEFAULT;
+    pub const sr: Steradian = Unit::DEFAULT;
+    pub const Hz: Hertz = Unit::DEFAULT;
+    pub const N: Newton = Unit::DEFAULT;
+    pub const Pa: Pascal = Unit::DEFAULT;
+    pub const J: Joule = Unit::DEFAULT;
+    pub const W: Watt = Unit::DEFAULT;
+    pub const C: Coulomb = Unit::DEFAULT;
+    pub const V: Volt = Unit::DEFAULT;
+    pub const F: Farad = Unit::DEFAULT;
+    pub const ohm: Ohm = Unit::DEFAULT;
+    pub const S: Siemens = Unit::DEFAULT;
+    pub const Wb: Weber = Unit::DEFAULT;
+    pub const T: Tesla = Unit::DEFAULT;
+    pub const H: Henry = Unit::DEFAULT;
+    pub const lm: Lumen = Unit::DEFAULT;
+    pub const lx: Lux = Unit::DEFAULT;
+    pub const Bq: Becquerel = Unit::DEFAULT;
+    pub const Gy: Gray = Unit::DEFAULT;
+    pub const Sv: Sievert = Unit::DEFAULT;
+    pub const kat: Katal = Unit::DEFAULT;
 }
 
 /// Implement `Mul<Unit<...>>` & `Div<Unit<...>>` operators for $type (e. g. `f32`)
@@ -262,17 +1002,17 @@ macro_rules! impl_mul_div_for_value_by_unit {
     ($type:ident, $feat:literal) => {
         // 10 * km
         //#[cfg(feature = $feat)]
-        impl<L, M, Ti, I, Te, N, J> ::core::ops::Mul<Unit<L, M, Ti, I, Te, N, J>> for $type {
-            type Output = $crate::Quantity<Unit<L, M, Ti, I, Te, N, J>, $type>;
+        impl<L, M, Ti, I, Te, N, J, An, R, Inf> ::core::ops::Mul<Unit<L, M, Ti, I, Te, N, J, An, R, Inf>> for $type {
+            type Output = $crate::Quantity<Unit<L, M, Ti, I, Te, N, J, An, R, Inf>, $type>;
 
-            fn mul(self, _: Unit<L, M, Ti, I, Te, N, J>) -> Self::Output {
+            fn mul(self, _: Unit<L, M, Ti, I, Te, N, J, An, R, Inf>) -> Self::Output {
                 Self::Output::new(self)
             }
         }
 
         // 10 / km = 10 * km^(-1)
         //#[cfg(feature = $feat)]
-        impl<L, M, Ti, I, Te, N, J> ::core::ops::Div<Unit<L, M, Ti, I, Te, N, J>> for $type
+        impl<L, M, Ti, I, Te, N, J, An, R, Inf> ::core::ops::Div<Unit<L, M, Ti, I, Te, N, J, An, R, Inf>> for $type
         where
             L: UnitInv,
             M: UnitInv,
@@ -281,11 +1021,14 @@ macro_rules! impl_mul_div_for_value_by_unit {
             Te: UnitInv,
             N: UnitInv,
             J: UnitInv,
+            An: UnitInv,
+            R: UnitInv,
+            Inf: UnitInv,
         {
             type Output =
-                $crate::Quantity<$crate::ops::Inverse<Unit<L, M, Ti, I, Te, N, J>>, $type>;
+                $crate::Quantity<$crate::ops::Inverse<Unit<L, M, Ti, I, Te, N, J, An, R, Inf>>, $type>;
 
-            fn div(self, _: Unit<L, M, Ti, I, Te, N, J>) -> Self::Output {
+            fn div(self, _: Unit<L, M, Ti, I, Te, N, J, An, R, Inf>) -> Self::Output {
                 Self::Output::new(self)
             }
         }
@@ -293,17 +1036,17 @@ macro_rules! impl_mul_div_for_value_by_unit {
     ($type:ident<$($generic:ident),+>, $feat:literal) => {
         // 10 * km
         //#[cfg(feature = $feat)]
-        impl<L, M, Ti, I, Te, N, J, $($generic),+> ::core::ops::Mul<Unit<L, M, Ti, I, Te, N, J>> for $type<$($generic),+> {
-            type Output = $crate::Quantity<Unit<L, M, Ti, I, Te, N, J>, $type<$($generic),+>>;
+        impl<L, M, Ti, I, Te, N, J, An, R, Inf, $($generic),+> ::core::ops::Mul<Unit<L, M, Ti, I, Te, N, J, An, R, Inf>> for $type<$($generic),+> {
+            type Output = $crate::Quantity<Unit<L, M, Ti, I, Te, N, J, An, R, Inf>, $type<$($generic),+>>;
 
-            fn mul(self, _: Unit<L, M, Ti, I, Te, N, J>) -> Self::Output {
+            fn mul(self, _: Unit<L, M, Ti, I, Te, N, J, An, R, Inf>) -> Self::Output {
                 Self::Output::new(self)
             }
         }
 
         // 10 / km = 10 * km^(-1)
         //#[cfg(feature = $feat)]
-        impl<L, M, Ti, I, Te, N, J, $($generic),+> ::core::ops::Div<Unit<L, M, Ti, I, Te, N, J>> for $type<$($generic),+>
+        impl<L, M, Ti, I, Te, N, J, An, R, Inf, $($generic),+> ::core::ops::Div<Unit<L, M, Ti, I, Te, N, J, An, R, Inf>> for $type<$($generic),+>
         where
             L: UnitInv,
             M: UnitInv,
@@ -312,11 +1055,14 @@ macro_rules! impl_mul_div_for_value_by_unit {
             Te: UnitInv,
             N: UnitInv,
             J: UnitInv,
+            An: UnitInv,
+            R: UnitInv,
+            Inf: UnitInv,
         {
             type Output =
-                $crate::Quantity<$crate::ops::Inverse<Unit<L, M, Ti, I, Te, N, J>>, $type<$($generic),+>>;
+                $crate::Quantity<$crate::ops::Inverse<Unit<L, M, Ti, I, Te, N, J, An, R, Inf>>, $type<$($generic),+>>;
 
-            fn div(self, _: Unit<L, M, Ti, I, Te, N, J>) -> Self::Output {
+            fn div(self, _: Unit<L, M, Ti, I, Te, N, J, An, R, Inf>) -> Self::Output {
                 Self::Output::new(self)
             }
         }
@@ -336,18 +1082,38 @@ impl_mul_div_for_value_by_unit!(u64, "u64");
 impl_mul_div_for_value_by_unit!(i128, "i128");
 impl_mul_div_for_value_by_unit!(u128, "u128");
 
+/// Implement lossless conversion of dimensionless quantities into plain values, e. g.
+/// `f64::from((3_f64 * m) / (2_f64 * m))` is `1.5`
+macro_rules! impl_from_dimensionless_for_value {
+    ($($type:ident),+) => {
+        $(impl From<Quantity<unit::Dimensionless, $type>> for $type {
+            fn from(quantity: Quantity<unit::Dimensionless, $type>) -> Self {
+                quantity.value
+            }
+        })+
+    };
+}
+
+impl_from_dimensionless_for_value!(f32, f64, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128);
+
 #[cfg(test)]
 mod tests {
     use super::{
-        consts::{m, m2, s},
-        unit::MeterPerSecond,
-    };
-    use crate::{
-        isq::{consts::kg, unit::Meter},
-        Quantity,
+        consts::{m, s, A, C, F, H, J, K, N, V, W},
+        prefix::{k, m as milli, mu},
+        root::{meter, second},
+        unit::{Hertz, Meter, MeterPerSecond, Newton, Ohm},
+        Unit,
     };
+    use crate::{base_unit::Pre, Const, Prefix, Quantity};
     // use nalgebra::{RowVector3, Vector3};
 
+    type Kilometer = Unit<(Pre<k, meter>, Const<1>)>;
+    type Millimeter = Unit<(Pre<milli, meter>, Const<1>)>;
+    type Micrometer = Unit<(Pre<mu, meter>, Const<1>)>;
+    type KilometerPerMillisecond =
+        Unit<(Pre<k, meter>, Const<1>), (), (Pre<milli, second>, Const<-1>)>;
+
     #[test]
     fn nalgebra_vec() {
         let l1 = 12_f32 * m;
@@ -355,4 +1121,437 @@ mod tests {
         let l3 = l1 + l2;
         println!("{l1}\n{l2}\n{l3}");
     }
+
+    #[test]
+    fn convert_prefixed_units() {
+        let km = Quantity::<Kilometer, i32>::new(5);
+        assert_eq!(km.into::<Meter>(), 5000 * m);
+        assert_eq!(Quantity::<Kilometer, i64>::from(7000_i64 * m), Quantity::new(7));
+        let mm = Quantity::<Millimeter, i32>::new(3);
+        assert_eq!(mm.into::<Micrometer>(), Quantity::new(3000));
+        assert_eq!(Quantity::<Micrometer, i32>::new(4000).into::<Millimeter>(), Quantity::new(4));
+    }
+
+    #[test]
+    fn prefix_factors() {
+        assert_eq!(<milli as Prefix<f64>>::FACTOR, (1.0, 1000.0));
+        assert_eq!(<k as Prefix<u32>>::FACTOR, (1000, 1));
+        assert_eq!(<mu as Prefix<f32>>::FACTOR, (1.0, 1e6));
+        let mm = Quantity::<Millimeter, f64>::new(1500.0);
+        assert_eq!(mm.into::<Meter>(), 1.5 * m);
+        assert_eq!(Quantity::<Kilometer, u16>::new(3).into::<Meter>(), 3000_u16 * m);
+    }
+
+    #[test]
+    fn unit_powers() {
+        assert_eq!(2_f64 * m.pow::<3>(), 2_f64 * (m * m * m));
+        assert_eq!(2_f64 * m.pow::<-2>(), 2_f64 / (m * m));
+        assert_eq!(2_f64 * s.recip(), 2_f64 / s);
+        assert_eq!(2_f64 * (m * m).sqrt(), 2_f64 * m);
+        assert_eq!(2_f64 * (m * m * m).cbrt(), 2_f64 * m);
+        assert_eq!(s.recip().sqrt().to_string(), "s^⁻½");
+        assert_eq!((m / s.recip().sqrt()).to_string(), "m⋅s^½");
+        assert_eq!(m.pow::<3>().sqrt().to_string(), "m^³⁄₂");
+        assert_eq!(s.cbrt().recip().pow::<2>().to_string(), "s^⁻⅔");
+    }
+
+    #[test]
+    fn wide_exponents() {
+        assert_eq!(m.pow::<4>().pow::<3>().to_string(), "m¹²");
+        assert_eq!(s.pow::<-3>().pow::<3>().to_string(), "s⁻⁹");
+        assert_eq!((m.pow::<100>() / s.pow::<27>()).to_string(), "m¹⁰⁰/s²⁷");
+        assert_eq!(2_f64 * (m.pow::<127>() / m.pow::<127>()), 2_f64 * (m / m));
+        assert_eq!(s.pow::<-128>().to_string(), "s⁻¹²⁸");
+    }
+
+    #[test]
+    fn convert_compound_prefixed_units() {
+        let speed = Quantity::<KilometerPerMillisecond, i64>::new(2);
+        assert_eq!(speed.into::<MeterPerSecond>(), 2_000_000_i64 * (m / s));
+    }
+
+    #[test]
+    fn derived_units() {
+        use super::consts::{kg, ohm, Hz, Pa, Wb, T};
+
+        let force: Quantity<Newton, f64> = 2.0 * (kg * m / (s * s));
+        assert_eq!(force, 2.0 * N);
+        assert_eq!(3.0 * (N * m), 3.0 * J);
+        assert_eq!(3.0 * (J / s), 3.0 * W);
+        assert_eq!(3.0 * (N / (m * m)), 3.0 * Pa);
+        assert_eq!(3.0 * (W / A), 3.0 * V);
+        assert_eq!(3.0 * (V / A), 3.0 * ohm);
+        assert_eq!(3.0 * (A * s), 3.0 * C);
+        assert_eq!(3.0 * (C / V), 3.0 * F);
+        assert_eq!(3.0 * (V * s), 3.0 * Wb);
+        assert_eq!(3.0 * (Wb / (m * m)), 3.0 * T);
+        assert_eq!(3.0 * (Wb / A), 3.0 * H);
+        assert_eq!(3.0 * Hz, 3.0 / s);
+        assert_eq!((2_f64 * V) / (4.0 * A), Quantity::<Ohm, f64>::new(0.5));
+        assert_eq!(Quantity::<Hertz, f64>::new(50.0).recip(), 0.02 * s);
+    }
+
+    #[test]
+    fn display_named_units() {
+        use super::consts::{cd, kg, mol, ohm, sr, Hz};
+
+        assert_eq!((1200_f64 * (kg * m * m / (s * s * s))).to_string(), "1200 W");
+        assert_eq!(format!("{:#}", 1200_f64 * W), "1200 (m²⋅kg)/s³");
+        assert_eq!(format!("{:#}", 2_f64 * N), "2 (m⋅kg)/s²");
+        assert_eq!((2_f64 * N).to_string(), "2 N");
+        assert_eq!((2_f64 * ohm).to_string(), "2 Ω");
+        assert_eq!((2_f64 * (V / m)).to_string(), "2 V/m");
+        assert_eq!((2_f64 * (N * m)).to_string(), "2 J");
+        assert_eq!((50_f64 / s).to_string(), "50 Hz");
+        assert_eq!((50_f64 * (Hz * Hz)).to_string(), "50 s⁻²");
+        assert_eq!((2_f64 * (mol / s)).to_string(), "2 kat");
+        assert_eq!((2_f64 * (cd * sr / (m * m))).to_string(), "2 lx");
+        assert_eq!((2_f64 * (cd / (m * m))).to_string(), "2 cd/m²");
+        assert_eq!((2_f64 * (m / s)).to_string(), "2 m/s");
+        assert_eq!((2_f64 * (W / (m * m))).to_string(), "2 W/m²");
+        assert_eq!((2_f64 * (J / (kg * K))).to_string(), "2 J/(kg⋅K)");
+        assert_eq!((2_f64 * (N * s)).to_string(), "2 N⋅s");
+    }
+
+    #[test]
+    fn debug_units() {
+        let unit = r#"Unit { L: Slot { root: "meter", prefix: None, exp: 1 }, dimension: L¹M⁰T⁰I⁰Θ⁰N⁰J⁰An⁰R⁰Inf⁰ }"#;
+        assert_eq!(format!("{m:?}"), unit);
+        assert_eq!(
+            format!("{:?}", Kilometer::new()),
+            r#"Unit { L: Slot { root: "meter", prefix: Some("kilo"), exp: 1 }, dimension: L¹M⁰T⁰I⁰Θ⁰N⁰J⁰An⁰R⁰Inf⁰ }"#
+        );
+        assert_eq!(
+            format!("{:?}", N),
+            concat!(
+                r#"Unit { L: Slot { root: "meter", prefix: None, exp: 1 }, "#,
+                r#"M: Slot { root: "gram", prefix: Some("kilo"), exp: 1 }, "#,
+                r#"T: Slot { root: "second", prefix: None, exp: -2 }, dimension: L¹M¹T⁻²I⁰Θ⁰N⁰J⁰An⁰R⁰Inf⁰ }"#,
+            )
+        );
+        assert_eq!(
+            format!("{:?}", s.sqrt()),
+            r#"Unit { T: Slot { root: "second", prefix: None, exp: 1/2 }, dimension: L⁰M⁰T½I⁰Θ⁰N⁰J⁰An⁰R⁰Inf⁰ }"#
+        );
+        // zero exponents of non-coherent roots are shown, as they are distinct types
+        assert_eq!(
+            format!("{:?}", Unit::<(Pre<k, meter>, Const<0>)>::new()),
+            r#"Unit { L: Slot { root: "meter", prefix: Some("kilo"), exp: 0 }, dimension: L⁰M⁰T⁰I⁰Θ⁰N⁰J⁰An⁰R⁰Inf⁰ }"#
+        );
+        assert_eq!(
+            format!("{:#?}", Kilometer::new()),
+            "Unit {\n    L: Slot {\n        root: \"meter\",\n        prefix: Some(\n            \"kilo\",\n        ),\n        exp: 1,\n    },\n    dimension: L¹M⁰T⁰I⁰Θ⁰N⁰J⁰An⁰R⁰Inf⁰,\n}"
+        );
+    }
+
+    #[test]
+    fn alternative_notations() {
+        use super::consts::{deg, degC, kg, ohm, pct};
+        use crate::notation::{Notation, Style};
+
+        let g = 9.81_f64 * (m / (s * s));
+        assert_eq!(format!("{:#}", g.format_with(Notation::Ascii)), "9.81 m/s^2");
+        assert_eq!(format!("{:#}", g.format_with(Notation::Long)), "9.81 meter per second squared");
+        assert_eq!(
+            format!("{:#}", g.format_with(Notation::Latex)),
+            r"\SI{9.81}{\metre\per\second\squared}"
+        );
+        assert_eq!(g.format_with(Notation::Unicode).to_string(), g.to_string());
+
+        let force = 2_f64 * N;
+        assert_eq!(format!("{:#}", force.format_with(Notation::Ascii)), "2 (m*kg)/s^2");
+        assert_eq!(
+            format!("{:#}", force.format_with(Notation::Long)),
+            "2 meter kilogram per second squared"
+        );
+        assert_eq!(
+            format!("{:#}", (kg * m / (s * s)).format_with(Notation::Latex)),
+            r"\si{\metre\kilo\gram\per\second\squared}"
+        );
+        assert_eq!(force.format_with(Notation::Long).to_string(), "2 newton");
+        assert_eq!((J / (kg * K)).format_with(Notation::Ascii).to_string(), "J/(kg*K)");
+        assert_eq!(
+            (J / (kg * K)).format_with(Notation::Long).to_string(),
+            "joule per kilogram Kelvin"
+        );
+        assert_eq!(
+            (J / (kg * K)).format_with(Notation::Latex).to_string(),
+            r"\si{\joule\per\kilo\gram\per\kelvin}"
+        );
+        assert_eq!(s.recip().format_with(Notation::Long).to_string(), "hertz");
+        assert_eq!((s * s).recip().format_with(Notation::Long).to_string(), "per second squared");
+        assert_eq!((s * s).recip().format_with(Notation::Ascii).to_string(), "s^-2");
+        assert_eq!(m.pow::<4>().format_with(Notation::Long).to_string(), "meter to the power 4");
+        assert_eq!(m.pow::<4>().format_with(Notation::Latex).to_string(), r"\si{\metre\tothe{4}}");
+        assert_eq!(s.sqrt().format_with(Notation::Ascii).to_string(), "s^(1/2)");
+        assert_eq!((2_f64 * ohm).format_with(Notation::Ascii).to_string(), "2 Ohm");
+        assert_eq!((90_f64 * deg).format_with(Notation::Ascii).to_string(), "90 deg");
+        assert_eq!((20_f64 * degC).format_with(Notation::Long).to_string(), "20 degree Celsius");
+        assert_eq!(
+            (20_f64 * degC).format_with(Notation::Latex).to_string(),
+            r"\SI{20}{\degreeCelsius}"
+        );
+        assert_eq!((5_f64 * pct).format_with(Notation::Latex).to_string(), r"\SI{5}{\percent}");
+
+        let current = 0.0012_f64 * A;
+        let scientific =
+            |notation| current.format_with(Style::new(notation).scientific()).to_string();
+        assert_eq!(scientific(Notation::Unicode), "1.2×10⁻³ A");
+        assert_eq!(scientific(Notation::Ascii), "1.2e-3 A");
+        assert_eq!(scientific(Notation::Long), "1.2×10⁻³ Ampere");
+        assert_eq!(scientific(Notation::Latex), r"\SI{1.2e-3}{\ampere}");
+        assert_eq!(
+            (1200_i32 * A).format_with(Style::default().scientific()).to_string(),
+            "1.2×10³ A"
+        );
+        assert_eq!((5_f64 * A).format_with(Style::default().scientific()).to_string(), "5 A");
+    }
+
+    #[test]
+    #[cfg(feature = "micro")]
+    fn prefixed_alternative_notations() {
+        use super::consts::{kg, um};
+        use crate::notation::Notation;
+
+        assert_eq!((3_f64 * um).format_with(Notation::Ascii).to_string(), "3 um");
+        assert_eq!((3_f64 * um).format_with(Notation::Long).to_string(), "3 micrometer");
+        assert_eq!((3_f64 * um).format_with(Notation::Latex).to_string(), r"\SI{3}{\micro\metre}");
+        assert_eq!((um / kg).format_with(Notation::Long).to_string(), "micrometer per kilogram");
+        assert_eq!("3 um".parse(), Ok(3_f64 * um));
+    }
+
+    #[test]
+    #[cfg(all(feature = "kilo", feature = "milli", feature = "micro", feature = "atto"))]
+    fn prefixed_units() {
+        use super::{
+            consts::{kHz, km, mA, mg, ms, r#as, us, Hz},
+            unit::{Kilometer, Microsecond, Milligram},
+        };
+        use crate::notation::Notation;
+
+        assert_eq!((2_f64 * km).into::<Meter>(), 2000_f64 * m);
+        assert_eq!(Quantity::<Kilometer, i32>::new(3), 3 * km);
+        assert_eq!((1500_i64 * us).into::<super::unit::Millisecond>(), 1_i64 * ms);
+        assert_eq!(Quantity::<Microsecond, f64>::new(2.0), 2_f64 * us);
+        assert_eq!((4_f64 * kHz).into::<Hertz>(), 4000_f64 * Hz);
+        assert_eq!((3_f64 * kHz).to_string(), "3 kHz");
+        assert_eq!(format!("{:#}", 3_f64 * kHz), "3 ms⁻¹");
+        assert_eq!((3_f64 * kHz).format_with(Notation::Ascii).to_string(), "3 kHz");
+        assert_eq!((3_f64 * kHz).format_with(Notation::Long).to_string(), "3 kilohertz");
+        assert_eq!((3_f64 * kHz).format_with(Notation::Latex).to_string(), r"\SI{3}{\kilo\hertz}");
+        assert_eq!((2_f64 / us).to_string(), "2 MHz");
+        assert_eq!((2_f64 / ms).engineering().to_string(), "2 kHz");
+        assert_eq!((5_u32 * mg).into::<Milligram>(), 5_u32 * mg);
+        assert_eq!((3_f32 * mA).to_string(), "3 mA");
+        assert_eq!((3_f32 * r#as).to_string(), "3 as");
+    }
+
+    #[test]
+    #[cfg(all(feature = "kilo", feature = "milli"))]
+    fn mul_div_mixed_prefixes() {
+        use super::{
+            consts::{km, ms},
+            unit::{Kilometer, Millisecond},
+        };
+
+        // operands are aligned to the finer prefix, whatever their order
+        assert_eq!((2_f64 * km) * (3_f64 * m), 6000_f64 * (m * m));
+        assert_eq!((3_f64 * m) * (2_f64 * km), 6000_f64 * (m * m));
+        assert_eq!((2_i64 * km) * (3_i64 * m), 6000_i64 * (m * m));
+        assert_eq!((2_i64 * m) * (3_i64 * km), 6000_i64 * (m * m));
+        assert_eq!((2_i64 * km) * (3000_i64 * m), 6_000_000_i64 * (m * m));
+        assert_eq!((3_i64 * km) / (2_i64 * m), 1500_i64 * (m / m));
+        assert_eq!((2_i32 * ms) * (3_i32 * s), 6000_i32 * (ms * ms));
+        assert_eq!((10_f64 * km) / (2_f64 * ms), 5_f64 * (km / ms));
+        assert_eq!((3_f32 * (km / ms)).to_string(), "3 km/ms");
+        assert_eq!((6_i32 * km) / (2_i32 * km), 3_i32 * (km / km));
+        assert_eq!(((3_i32 * km) / (1500_i32 * m)).value, 2);
+        assert_eq!(i32::from((3_i32 * km) / (1500_i32 * m)), 2);
+        assert_eq!(f64::from((2_f64 * (km / ms)) * (3_f64 * (ms / m))), 6000.0);
+        assert_eq!((4_f64 * (km / s)) * (500_f64 * ms), 2_f64 * km);
+        assert_eq!(
+            Quantity::<Kilometer, i64>::new(3) / Quantity::<Millisecond, i64>::new(1),
+            3_i64 * (km / ms)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "kilo")]
+    fn mul_div_without_conversion() {
+        use super::{consts::km, unit::Second};
+        use core::ops::{Div, Mul};
+
+        // `kilo` doesn't fit into `u8`, but values of the same roots aren't converted
+        assert_eq!((3_u8 * m) * (2_u8 * s), 6_u8 * (m * s));
+        assert_eq!((6_u8 * m) / (2_u8 * s), 3_u8 * (m / s));
+        assert_eq!((3_u8 * km) * (2_u8 * km), 6_u8 * (km * km));
+        assert_eq!((6_u8 * km) / (2_u8 * (km / s)), 3_u8 * s);
+
+        /// Storage, which has no conversions & only operators on references
+        #[derive(Debug, PartialEq)]
+        struct Big(i128);
+
+        impl Mul<&Big> for &Big {
+            type Output = Big;
+
+            fn mul(self, rhs: &Big) -> Big {
+                Big(self.0 * rhs.0)
+            }
+        }
+
+        impl Div<&Big> for &Big {
+            type Output = Big;
+
+            fn div(self, rhs: &Big) -> Big {
+                Big(self.0 / rhs.0)
+            }
+        }
+
+        let (length, time) =
+            (Quantity::<Meter, _>::new(Big(6)), Quantity::<Second, _>::new(Big(2)));
+        assert_eq!((&length * &time).value, Big(12));
+        assert_eq!((&length / &time).value, Big(3));
+        assert_eq!((&length * &length).value, Big(36));
+    }
+
+    #[test]
+    fn convert_imperial_and_astronomical_lengths() {
+        use super::{
+            consts::{ft, inch, yd, AU},
+            unit::{Foot, Inch, Yard},
+        };
+
+        assert_eq!((3_f64 * ft).into::<Meter>(), 0.9144 * m);
+        assert_eq!((1_i64 * yd).into::<Foot>(), 3_i64 * ft);
+        assert_eq!((1_i64 * ft).into::<Inch>(), 12_i64 * inch);
+        assert_eq!((9144_i64 * m).into::<Yard>(), 10_000_i64 * yd);
+        assert_eq!((2_i64 * AU).into::<Meter>(), 299_195_741_400_i64 * m);
+        assert_eq!((1_f32 * AU).into::<Meter>(), 149_597_870_700_f32 * m);
+        assert_eq!(
+            (10_f64 * (ft / s)).into::<MeterPerSecond>(),
+            Quantity::<MeterPerSecond, f64>::new(3.048)
+        );
+        assert_eq!((2_f64 * ft) * (6_f64 * inch), 144_f64 * (inch * inch));
+        assert_eq!((6_f64 * inch) * (2_f64 * ft), 144_f64 * (inch * inch));
+        assert_eq!((1_i64 * yd) * (2_i64 * ft), 6_i64 * (ft * ft));
+        assert_eq!((3_f64 * ft).to_string(), "3 ft");
+    }
+
+    #[test]
+    fn convert_time_units() {
+        use super::{
+            consts::{d, h, min, wk, yr},
+            unit::{Day, Hour, Minute, Second, Week},
+        };
+
+        assert_eq!((2_i32 * h).into::<Second>(), 7200_i32 * s);
+        assert_eq!((90_u16 * min).into::<Hour>(), 1_u16 * h);
+        assert_eq!((1.5_f64 * d).into::<Minute>(), 2160_f64 * min);
+        assert_eq!((2_i64 * wk).into::<Day>(), 14_i64 * d);
+        // `604800 s` doesn't fit into `i16`, but the ratio of `wk` & `d` does
+        assert_eq!((1_i16 * wk).into::<Day>(), 7_i16 * d);
+        assert_eq!((3_i16 * d).into::<Week>(), 0_i16 * wk);
+        assert_eq!((4_i64 * yr).into::<Day>(), 1461_i64 * d);
+        assert_eq!((1_f64 * yr).to_string(), "1 a");
+        assert_eq!((3_f64 * (m / min)).to_string(), "3 m/min");
+    }
+
+    #[test]
+    #[cfg(feature = "kilo")]
+    fn checked_conversions() {
+        use super::{
+            consts::{h, km},
+            unit::{Hour, Kilometer, MeterPerSecond, Second},
+        };
+
+        assert_eq!((200_u8 * km).checked_into::<Meter>(), None);
+        assert_eq!((2_u8 * km).checked_into::<Meter>(), None);
+        assert_eq!((2_u16 * km).checked_into::<Meter>(), Some(2000_u16 * m));
+        assert_eq!((i128::MAX * km).checked_into::<Meter>(), None);
+        assert_eq!((u128::MAX * h).checked_into::<Second>(), None);
+        assert_eq!((7200_i128 * s).checked_into::<Hour>(), Some(2_i128 * h));
+        assert_eq!((-1_i32 * km).checked_into::<Meter>(), Some(-1000_i32 * m));
+        assert_eq!(Quantity::<Kilometer, u32>::checked_from(5000_u32 * m), Some(5_u32 * km));
+        assert_eq!(
+            Quantity::<MeterPerSecond, i32>::checked_from(36_i32 * (km / h)),
+            Some(Quantity::new(10))
+        );
+        assert_eq!(Quantity::<MeterPerSecond, i16>::checked_from(40_i16 * (km / s)), None);
+        assert_eq!((1e300_f64 * km).checked_into::<Meter>(), Some(1e303 * m));
+    }
+
+    #[test]
+    #[cfg(feature = "kilo")]
+    #[should_panic(expected = "converted value doesn't fit into storage type")]
+    fn conversion_overflow_panics() {
+        let _ = (200_u8 * super::consts::km).into::<Meter>();
+    }
+
+    #[test]
+    fn angles() {
+        use super::{
+            consts::{arcmin, arcsec, deg, rad, tr},
+            unit::{Arcminute, Arcsecond, Degree, Dimensionless, Radian, RadianPerSecond, Turn},
+        };
+        use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+        assert_eq!((180_f64 * deg).into::<Radian>(), PI * rad);
+        assert_eq!((PI * rad).into::<Degree>(), 180_f64 * deg);
+        assert_eq!((1_f64 * deg).into::<Arcminute>(), 60_f64 * arcmin);
+        assert_eq!((1_f64 * arcmin).into::<Arcsecond>(), 60_f64 * arcsec);
+        assert_eq!((1_f64 * tr).into::<Degree>(), 360_f64 * deg);
+        assert_eq!((90_f64 * deg).into::<Turn>(), 0.25 * tr);
+
+        assert!(((30_f64 * deg).sin().value - 0.5).abs() < 1e-12);
+        assert!(((60_f32 * deg).cos().value - 0.5).abs() < 1e-6);
+        assert!(((0.125_f64 * tr).tan().value - 1.0).abs() < 1e-12);
+        assert!(((FRAC_PI_2 * rad).sin().value - 1.0).abs() < 1e-12);
+
+        let (x, y) = (3_f64 * m, 3_f64 * m);
+        assert_eq!(y.atan2(x), FRAC_PI_4 * rad);
+        assert_eq!(Quantity::<Dimensionless, f64>::new(1.0).asin(), FRAC_PI_2 * rad);
+        assert_eq!(Quantity::<Dimensionless, f64>::new(1.0).acos(), 0_f64 * rad);
+        assert_eq!((2_f64 * (m / m)).atan().into::<Degree>().value.round(), 63.0);
+
+        let spin: Quantity<RadianPerSecond, f64> = (2_f64 * tr).into::<Radian>() / (1_f64 * s);
+        assert_eq!(spin, 4.0 * PI * (rad / s));
+        assert_eq!((2_f64 * (rad / s)).to_string(), "2 rad/s");
+        assert_eq!((2_f64 * (rad * rad)).to_string(), "2 sr");
+        #[cfg(feature = "milli")]
+        assert_eq!((1500_f64 * super::consts::mrad).into::<Radian>(), 1.5 * rad);
+    }
+
+    #[test]
+    fn dimensionless_and_ratios() {
+        use super::{
+            consts::{pct, permille, ppm},
+            unit::{Dimensionless, PartsPerMillion, Percent, Permille},
+        };
+        use core::f64::consts::E;
+
+        let ratio: Quantity<Dimensionless, f64> = (3_f64 * m) / (2_f64 * m);
+        assert_eq!(f64::from(ratio), 1.5);
+        assert_eq!(i32::from((6_i32 * (m * s)) / (2_i32 * (s * m))), 3);
+        assert_eq!(f64::from((2_f64 * m).powi::<0>()), 1.0);
+        assert_eq!((3_f64 * m / (2_f64 * m)).to_string(), "1.5");
+        assert_eq!(format!("{ratio:#}|{ratio:>5}|{ratio:e}"), "1.5|  1.5|1.5e0");
+        assert_eq!(ratio.format_with(crate::notation::Notation::Ascii).to_string(), "1.5");
+        assert_eq!(ratio.format_with(crate::notation::Notation::Long).to_string(), "1.5");
+        assert_eq!(ratio.engineering().to_string(), "1.5");
+
+        assert_eq!((50_f64 * pct).into::<Dimensionless>(), Quantity::new(0.5));
+        assert_eq!(Quantity::<Dimensionless, f64>::new(0.25).into::<Percent>(), 25_f64 * pct);
+        assert_eq!((5_i32 * pct).into::<Permille>(), 50_i32 * permille);
+        assert_eq!((3_i64 * permille).into::<PartsPerMillion>(), 3000_i64 * ppm);
+        assert_eq!((10_f64 * pct) / (5_f64 * pct), Quantity::<Dimensionless, f64>::new(2.0));
+        assert_eq!(((5_f64 * pct) * (200_f64 * m)).into::<Meter>(), 10_f64 * m);
+        assert_eq!((5_f64 * pct).to_string(), "5 %");
+        assert_eq!((5_f64 * permille).to_string(), "5 ‰");
+
+        let decay = (2_f64 * s) / (2_f64 * s);
+        assert!((f64::from((-decay).exp()) - 1.0 / E).abs() < 1e-12);
+        assert_eq!(f64::from(Quantity::<Dimensionless, f64>::new(E).ln()), 1.0);
+        assert_eq!(f32::from(Quantity::<Dimensionless, f32>::new(1000.0).log10()), 3.0);
+        assert_eq!(f64::from(Quantity::<Dimensionless, f64>::new(0.0).tanh()), 0.0);
+    }
 }
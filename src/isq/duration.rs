@@ -0,0 +1,179 @@
+//! Conversions between time quantities & `core::time::Duration`
+//!
+//! `Duration` can't be negative, so conversion into it is fallible. Integer quantities are
+//! converted via nanoseconds, so e. g. `1500 ms` is exactly `1.5 s`, while float quantities
+//! are converted via seconds.
+
+use super::{prefix::nano, root::second, unit::TimeUnit};
+use crate::{
+    base_unit::{ConvertFrom, Factor, Pre, Rescale},
+    kind, Quantity,
+};
+use core::{
+    fmt::{self, Display, Formatter},
+    time::Duration,
+};
+
+type Nanosecond = Pre<nano, second>;
+
+const NANOS_PER_SEC: i128 = 1_000_000_000;
+
+/// Time quantity can't be represented by `Duration`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DurationError {
+    /// `Duration` can't be negative
+    Negative,
+    /// `NaN` or infinity
+    NotFinite,
+    /// Value doesn't fit into `Duration` or into storage type
+    Overflow,
+}
+
+impl Display for DurationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Negative => write!(f, "negative time can't be converted into duration"),
+            Self::NotFinite => write!(f, "non-finite time can't be converted into duration"),
+            Self::Overflow => write!(f, "time is out of range"),
+        }
+    }
+}
+
+impl std::error::Error for DurationError {}
+
+/// `Duration` from whole nanoseconds
+fn duration_from_nanos(nanos: i128) -> Result<Duration, DurationError> {
+    if nanos < 0 {
+        return Err(DurationError::Negative);
+    }
+    let secs = u64::try_from(nanos / NANOS_PER_SEC).map_err(|_| DurationError::Overflow)?;
+    Ok(Duration::new(secs, (nanos % NANOS_PER_SEC) as u32))
+}
+
+/// Implement conversions between `Quantity<TimeUnit<_>, $type>` & `Duration`
+/// for integer `$type`, e. g. `Duration::try_from(1500_i64 * ms)`
+macro_rules! impl_duration_conversions_for_int {
+    ($($type:ident),+) => {
+        $(impl<R> TryFrom<Quantity<TimeUnit<R>, $type>> for Duration
+        where
+            R: kind::Time + Factor<i128, Coherent = second>,
+        {
+            type Error = DurationError;
+
+            fn try_from(time: Quantity<TimeUnit<R>, $type>) -> Result<Self, Self::Error> {
+                let value = i128::try_from(time.value).map_err(|_| DurationError::Overflow)?;
+                let nanos = value.checked_rescale(R::factor(), Nanosecond::factor());
+                duration_from_nanos(nanos.ok_or(DurationError::Overflow)?)
+            }
+        }
+
+        /// Rounds towards zero, e. g. `1.5 s` is `1 s`
+        impl<R: kind::Time + Factor<i128, Coherent = second>> TryFrom<Duration>
+            for Quantity<TimeUnit<R>, $type>
+        {
+            type Error = DurationError;
+
+            fn try_from(duration: Duration) -> Result<Self, Self::Error> {
+                // `u64::MAX` seconds in nanoseconds fit into `i128`
+                let nanos = duration.as_nanos() as i128;
+                let value = nanos.checked_rescale(Nanosecond::factor(), R::factor());
+                let value = value.ok_or(DurationError::Overflow)?;
+                $type::try_from(value).map(Self::new).map_err(|_| DurationError::Overflow)
+            }
+        })+
+    };
+}
+
+impl_duration_conversions_for_int!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128);
+
+/// Implement conversions between `Quantity<TimeUnit<_>, $type>` & `Duration`
+/// for float `$type`, e. g. `Duration::try_from(1.5 * s)`
+macro_rules! impl_duration_conversions_for_float {
+    ($(($type:ident, $try_from_secs:ident, $as_secs:ident),)+) => {
+        $(impl<R: kind::Time> TryFrom<Quantity<TimeUnit<R>, $type>> for Duration
+        where
+            second: ConvertFrom<R, $type>,
+        {
+            type Error = DurationError;
+
+            fn try_from(time: Quantity<TimeUnit<R>, $type>) -> Result<Self, Self::Error> {
+                let secs = second::convert_from(time.value);
+                if !secs.is_finite() {
+                    return Err(DurationError::NotFinite);
+                }
+                if secs < 0.0 {
+                    return Err(DurationError::Negative);
+                }
+                Duration::$try_from_secs(secs).map_err(|_| DurationError::Overflow)
+            }
+        }
+
+        impl<R: kind::Time + ConvertFrom<second, $type>> From<Duration>
+            for Quantity<TimeUnit<R>, $type>
+        {
+            fn from(duration: Duration) -> Self {
+                Self::new(R::convert_from(duration.$as_secs()))
+            }
+        })+
+    };
+}
+
+impl_duration_conversions_for_float! {
+    (f32, try_from_secs_f32, as_secs_f32),
+    (f64, try_from_secs_f64, as_secs_f64),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DurationError;
+    use crate::{
+        isq::{
+            consts::{d, h, min, s},
+            unit::{Hour, Minute, Second},
+        },
+        Quantity,
+    };
+    use core::time::Duration;
+
+    #[test]
+    fn quantity_into_duration() {
+        assert_eq!(Duration::try_from(1.5 * s), Ok(Duration::from_millis(1500)));
+        assert_eq!(Duration::try_from(2_u32 * h), Ok(Duration::from_secs(7200)));
+        assert_eq!(Duration::try_from(0.25_f32 * min), Ok(Duration::from_secs(15)));
+        assert_eq!(Duration::try_from(-1_i32 * s), Err(DurationError::Negative));
+        assert_eq!(Duration::try_from(-0.5 * d), Err(DurationError::Negative));
+        assert_eq!(Duration::try_from(f64::NAN * s), Err(DurationError::NotFinite));
+        assert_eq!(Duration::try_from(f64::INFINITY * h), Err(DurationError::NotFinite));
+        assert_eq!(Duration::try_from(1e30 * s), Err(DurationError::Overflow));
+        assert_eq!(Duration::try_from(u128::MAX * d), Err(DurationError::Overflow));
+        assert_eq!(Duration::try_from((i128::MAX / 2) * s), Err(DurationError::Overflow));
+    }
+
+    #[test]
+    fn duration_into_quantity() {
+        let duration = Duration::from_millis(90_000);
+        // `Quantity::from` is a unit conversion, so `Into` is used
+        let secs: Quantity<Second, f64> = duration.into();
+        assert_eq!(secs, 90_f64 * s);
+        let mins: Quantity<Minute, f32> = duration.into();
+        assert_eq!(mins, 1.5_f32 * min);
+        assert_eq!(Quantity::<Minute, i64>::try_from(duration), Ok(1_i64 * min));
+        assert_eq!(
+            Quantity::<Hour, u8>::try_from(Duration::from_secs(3600 * 300)),
+            Err(DurationError::Overflow)
+        );
+        assert_eq!(Quantity::<Second, i8>::try_from(Duration::from_secs(127)), Ok(127_i8 * s));
+    }
+
+    #[test]
+    #[cfg(feature = "milli")]
+    fn prefixed_time_into_duration() {
+        use crate::isq::{consts::ms, unit::Millisecond};
+
+        assert_eq!(Duration::try_from(1500_i64 * ms), Ok(Duration::from_millis(1500)));
+        assert_eq!(
+            Quantity::<Millisecond, u64>::try_from(Duration::from_micros(2500)),
+            Ok(2_u64 * ms)
+        );
+    }
+}
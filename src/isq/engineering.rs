@@ -0,0 +1,193 @@
+//! Automatic choice of metric prefixes for display, e. g. `0.0012 s` is `1.2 ms`
+//!
+//! The leading unit of the numerator is prefixed, e. g. `1200 m/s` is `1.2 km/s`, so that
+//! the mantissa is in `[1, 1000)` for engineering prefixes. The largest prefix, which keeps
+//! the mantissa `>= 1`, wins, unless there is none, e. g. for `1e-30 m`.
+
+use super::{
+    fmt_units,
+    prefix::{
+        atto, centi, deca, deci, exa, femto, giga, hecto, kilo, mega, micro, milli, nano, peta,
+        pico, tera, yocto, yotta, zepto, zetta,
+    },
+    ExpUnit, Unit,
+};
+use crate::{
+    base_unit::Exponent,
+    name::Named,
+    notation::Notation,
+    ops::ToF64,
+    util::{format_value, pad, with_unit},
+    Prefix, Quantity,
+};
+use core::fmt::{self, Display, Formatter};
+
+/// Metric prefix as a value, e. g. `MetricPrefix::of::<kilo>()`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MetricPrefix {
+    pub short: &'static str,
+    pub full: &'static str,
+    /// Power of `10`, e. g. `3` for `kilo`
+    pub exp: i8,
+}
+
+impl MetricPrefix {
+    #[must_use]
+    pub const fn of<P: Prefix<f64>>() -> Self {
+        Self { short: P::SHORT, full: P::FULL, exp: <P as Prefix<f64>>::EXP }
+    }
+}
+
+macro_rules! prefix_table {
+    ($($prefix:ident),+) => {
+        [$(MetricPrefix::of::<$prefix>()),+]
+    };
+}
+
+/// All metric prefixes, e. g. for restricting with `Iterator::filter`
+#[rustfmt::skip]
+pub const ALL: [MetricPrefix; 20] = prefix_table!(
+    yocto, zepto, atto, femto, pico, nano, micro, milli, centi, deci,
+    deca, hecto, kilo, mega, giga, tera, peta, exa, zetta, yotta
+);
+
+/// Prefixes, which are powers of `1000`, the default
+#[rustfmt::skip]
+pub const ENGINEERING: [MetricPrefix; 16] = prefix_table!(
+    yocto, zepto, atto, femto, pico, nano, micro, milli,
+    kilo, mega, giga, tera, peta, exa, zetta, yotta
+);
+
+/// Quantity, which is displayed with an automatically chosen prefix, e. g. `1.2 ms`
+///
+/// `{:#}` prints SI base units only like `Display` of `Quantity`, sign & precision flags apply
+/// to the mantissa, width to the whole.
+#[derive(Clone, Copy)]
+pub struct Engineering<'a, U, V> {
+    quantity: Quantity<U, V>,
+    prefixes: &'a [MetricPrefix],
+}
+
+impl<U, V> Quantity<U, V> {
+    /// E. g. `(0.0012 * s).engineering()` is displayed as `1.2 ms`
+    pub fn engineering(self) -> Engineering<'static, U, V> {
+        Engineering { quantity: self, prefixes: &ENGINEERING }
+    }
+}
+
+impl<U, V> Engineering<'_, U, V> {
+    /// Restricts prefixes to a subset, e. g. of `ALL` without `deca` & `hecto`,
+    /// the unit may stay unprefixed anyway
+    pub fn with_prefixes(self, prefixes: &[MetricPrefix]) -> Engineering<'_, U, V> {
+        Engineering { quantity: self.quantity, prefixes }
+    }
+}
+
+/// Product or quotient of units in Unicode notation
+struct Units(Vec<ExpUnit>);
+
+impl Display for Units {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_units(f, self.0.clone(), Notation::Unicode)
+    }
+}
+
+/// `value` multiplied by `10^exp` in a single rounding
+fn shift(value: f64, exp: i32) -> f64 {
+    if exp >= 0 {
+        value * 10_f64.powi(exp)
+    } else {
+        value / 10_f64.powi(-exp)
+    }
+}
+
+impl<L, M, Ti, I, Te, N, J, An, R, Inf, V> Display
+    for Engineering<'_, Unit<L, M, Ti, I, Te, N, J, An, R, Inf>, V>
+where
+    L: Named + Exponent,
+    M: Named + Exponent,
+    Ti: Named + Exponent,
+    I: Named + Exponent,
+    Te: Named + Exponent,
+    N: Named + Exponent,
+    J: Named + Exponent,
+    An: Named + Exponent,
+    R: Named + Exponent,
+    Inf: Named + Exponent,
+    V: ToF64 + Copy,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut units = Unit::<L, M, Ti, I, Te, N, J, An, R, Inf>::exp_units(!f.alternate());
+        let mut value = self.quantity.value.to_f64();
+
+        let leading = units.iter_mut().find(|unit| unit.exp > 0 && unit.den == 1);
+        // prefix of the leading unit, e. g. `kilo` of `kg`, unless it isn't metric, e. g. `Ki`
+        let current = leading.as_ref().and_then(|unit| match unit.name.prefix {
+            None => Some(0),
+            Some(prefix) => {
+                ALL.iter().find(|metric| (metric.short, metric.full) == prefix).map(|p| p.exp)
+            }
+        });
+        if let (Some(unit), Some(current)) = (leading, current) {
+            if value != 0.0 && value.is_finite() {
+                let unit_exp = i32::from(unit.exp);
+                // value without prefix, e. g. in `g` instead of `kg`
+                let base = shift(value, i32::from(current) * unit_exp);
+                let candidates = self.prefixes.iter().map(Some).chain([None]);
+                let exp = |prefix: Option<&MetricPrefix>| prefix.map_or(0, |p| i32::from(p.exp));
+                let fits = candidates
+                    .clone()
+                    .filter(|prefix| shift(base.abs(), -exp(*prefix) * unit_exp) >= 1.0)
+                    .max_by_key(|prefix| exp(*prefix));
+                let prefix = fits.or_else(|| candidates.min_by_key(|prefix| exp(*prefix)));
+                if let Some(prefix) = prefix {
+                    value = shift(base, -exp(prefix) * unit_exp);
+                    unit.name.prefix = prefix.map(|p| (p.short, p.full));
+                }
+            }
+        }
+
+        pad(f, &with_unit(&format_value!(f, value, ""), Units(units)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MetricPrefix, ALL};
+    use crate::isq::{
+        consts::{kg, m, s, A, N},
+        prefix::{deca, hecto},
+    };
+
+    #[test]
+    fn engineering_prefixes() {
+        assert_eq!((0.0012_f64 * s).engineering().to_string(), "1.2 ms");
+        assert_eq!((1200_f64 * m).engineering().to_string(), "1.2 km");
+        assert_eq!((1500_i32 * m).engineering().to_string(), "1.5 km");
+        assert_eq!((999_f64 * m).engineering().to_string(), "999 m");
+        assert_eq!((-0.000_047_f64 * A).engineering().to_string(), "-47 μA");
+        assert_eq!((0.5_f64 * kg).engineering().to_string(), "500 g");
+        assert_eq!((2_500_000_f64 * kg).engineering().to_string(), "2.5 Gg");
+        assert_eq!((2500_f64 * N).engineering().to_string(), "2.5 kN");
+        assert_eq!(format!("{:#}", (2500_f64 * N).engineering()), "2.5 (km⋅kg)/s²");
+        assert_eq!((1200_f64 * (m / s)).engineering().to_string(), "1.2 km/s");
+        assert_eq!((3_000_000_f64 * (m * m)).engineering().to_string(), "3 km²");
+        assert_eq!(format!("{:.6}", (1e-30_f64 * m).engineering()), "0.000001 ym");
+        assert_eq!((0_f64 * s).engineering().to_string(), "0 s");
+        assert_eq!(format!("{:.2}", (0.0012_f64 * s).engineering()), "1.20 ms");
+        assert_eq!(format!("{:>8}", (0.0012_f64 * s).engineering()), "  1.2 ms");
+        assert_eq!(format!("{:+}", (5_f64 * m).engineering()), "+5 m");
+        assert_eq!(format!("{:+.1}", (1200_f64 * m).engineering()), "+1.2 km");
+    }
+
+    #[test]
+    fn restricted_prefixes() {
+        let common = [MetricPrefix::of::<hecto>(), MetricPrefix::of::<deca>()];
+        let no_deca_hecto: Vec<_> = ALL.into_iter().filter(|p| !common.contains(p)).collect();
+
+        assert_eq!((150_f64 * m).engineering().with_prefixes(&ALL).to_string(), "1.5 hm");
+        assert_eq!((150_f64 * m).engineering().with_prefixes(&no_deca_hecto).to_string(), "150 m");
+        assert_eq!((0.05_f64 * m).engineering().with_prefixes(&no_deca_hecto).to_string(), "5 cm");
+        assert_eq!((0.0012_f64 * s).engineering().with_prefixes(&[]).to_string(), "0.0012 s");
+    }
+}
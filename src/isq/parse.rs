@@ -0,0 +1,514 @@
+//! Parsing of quantities, e. g. `"9.81 m/s²"`, `"9.81 m/s^2"` or `"36 km/h"`
+//!
+//! Symbols are short names, their ASCII spellings, e. g. `um` for `μm`, or full names of roots
+//! & derived units with special names, with or without prefix, e. g. `km`, `kilometer` or `kN`. Factors are separated by `*`, `⋅`,
+//! `·` or whitespace, exponents are either superscripts or follow `^`. The parsed unit
+//! must have the dimension of the target one, into which the value is converted.
+
+use super::{
+    prefix,
+    root::{
+        ampere, arcminute, arcsecond, candela, day, degree, foot, gram, hour, inch, meter, minute,
+        mole, one, percent, permille, radian, second, turn, week, yard, AstroUnit, Celsius,
+        Fahrenheit, JulianYear, Kelvin, PartPerMillion, Rankine,
+    },
+    unit::NAMED,
+    Kg, Unit,
+};
+use crate::{
+    base_unit::{Factor, Rescale, SlotFactor},
+    iec_80000,
+    information::{Bit, Byte},
+    name::ascii,
+    ops::FromF64,
+    Name, Prefix, Quantity,
+};
+use core::{
+    fmt::{self, Display, Formatter},
+    iter::Peekable,
+    str::FromStr,
+};
+
+/// Number of slots in `Unit`
+const SLOTS: usize = 10;
+/// Slot of ratios, e. g. `%`, which are dimensionless, so that only their factors matter
+const RATIO: usize = 8;
+
+/// Quantity can't be parsed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseQuantityError {
+    /// Missing or malformed number, e. g. `"m/s"` or `"1.2.3 m/s"`
+    InvalidNumber,
+    /// Malformed unit, e. g. `"9.81 m/(s"` or `"9.81 m/s^"`
+    InvalidUnit,
+    /// Neither a root nor a derived unit with special name, with or without prefix
+    UnknownSymbol(String),
+    /// Unit of a different dimension, e. g. `"3 m/s"` for acceleration
+    DimensionMismatch,
+    /// Conversion factor can't be represented by storage type, e. g. `"90 °"` in integer radians
+    UnsupportedConversion,
+    /// Value doesn't fit into storage type after conversion, e. g. `"3000000 km"` in `i32` meters
+    Overflow,
+    /// Exponent doesn't fit into `i8`, e. g. `"1 m^200"` or `"1 (m^100)^2"`
+    ExponentOutOfRange,
+}
+
+impl Display for ParseQuantityError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidNumber => write!(f, "invalid number"),
+            Self::InvalidUnit => write!(f, "invalid unit"),
+            Self::UnknownSymbol(symbol) => write!(f, "unknown unit symbol `{symbol}`"),
+            Self::DimensionMismatch => write!(f, "unit has a different dimension"),
+            Self::UnsupportedConversion => {
+                write!(f, "conversion factor can't be represented by storage type")
+            }
+            Self::Overflow => write!(f, "value doesn't fit into storage type"),
+            Self::ExponentOutOfRange => write!(f, "exponent is out of range"),
+        }
+    }
+}
+
+impl std::error::Error for ParseQuantityError {}
+
+/// Roots' short & full names, slots & conversion factors
+macro_rules! root_table {
+    ($(($slot:literal, [$($root:ty),+ $(,)?]),)+) => {
+        &[$($((
+            <$root as Name>::SHORT,
+            <$root as Name>::FULL,
+            $slot,
+            <$root as Factor<f64>>::factor,
+        )),+),+]
+    };
+}
+
+/// Short & full names, slot & conversion factor of a root
+type RootEntry = (&'static str, &'static str, usize, fn() -> (f64, f64));
+
+#[rustfmt::skip]
+const ROOTS: &[RootEntry] = root_table! {
+    (0, [meter, foot, yard, inch, AstroUnit]),
+    (1, [gram]),
+    (2, [second, minute, hour, day, week, JulianYear]),
+    (3, [ampere]),
+    (4, [Kelvin, Celsius, Fahrenheit, Rankine]),
+    (5, [mole]),
+    (6, [candela]),
+    (7, [radian, degree, arcminute, arcsecond, turn]),
+    (8, [one, percent, permille, PartPerMillion]),
+    (9, [Bit, Byte]),
+};
+
+/// Prefixes' short & full names & factors
+macro_rules! prefix_table {
+    ($($prefix:ty),+ $(,)?) => {
+        &[$((<$prefix as Name>::SHORT, <$prefix as Name>::FULL, <$prefix as Prefix<f64>>::FACTOR)),+]
+    };
+}
+
+const PREFIXES: &[(&str, &str, (f64, f64))] = prefix_table!(
+    prefix::yocto,
+    prefix::zepto,
+    prefix::atto,
+    prefix::femto,
+    prefix::pico,
+    prefix::nano,
+    prefix::micro,
+    prefix::milli,
+    prefix::centi,
+    prefix::deci,
+    prefix::deca,
+    prefix::hecto,
+    prefix::kilo,
+    prefix::mega,
+    prefix::giga,
+    prefix::tera,
+    prefix::peta,
+    prefix::exa,
+    prefix::zetta,
+    prefix::yotta,
+    iec_80000::prefix::kibi,
+    iec_80000::prefix::mebi,
+    iec_80000::prefix::gibi,
+    iec_80000::prefix::tebi,
+    iec_80000::prefix::pebi,
+    iec_80000::prefix::exbi,
+    iec_80000::prefix::zebi,
+    iec_80000::prefix::yobi,
+);
+
+/// Unit as exponents of slots & conversion factor `(numerator, denominator)` into coherent units
+#[derive(Clone, Copy, Debug)]
+struct Term {
+    exps: [i8; SLOTS],
+    factor: (f64, f64),
+}
+
+impl Term {
+    const ONE: Self = Self { exps: [0; SLOTS], factor: (1.0, 1.0) };
+
+    fn mul(self, rhs: Self) -> Result<Self, ParseQuantityError> {
+        let mut exps = self.exps;
+        for (exp, rhs) in exps.iter_mut().zip(rhs.exps) {
+            *exp = exp.checked_add(rhs).ok_or(ParseQuantityError::ExponentOutOfRange)?;
+        }
+        Ok(Self { exps, factor: (self.factor.0 * rhs.factor.0, self.factor.1 * rhs.factor.1) })
+    }
+
+    fn div(self, rhs: Self) -> Result<Self, ParseQuantityError> {
+        self.mul(rhs.pow(-1)?)
+    }
+
+    fn pow(self, exp: i8) -> Result<Self, ParseQuantityError> {
+        let mut exps = self.exps;
+        for slot in &mut exps {
+            *slot = slot.checked_mul(exp).ok_or(ParseQuantityError::ExponentOutOfRange)?;
+        }
+        let ((num, den), exp) = (self.factor, i32::from(exp));
+        let factor = if exp >= 0 {
+            (num.powi(exp), den.powi(exp))
+        } else {
+            (den.powi(-exp), num.powi(-exp))
+        };
+        Ok(Self { exps, factor })
+    }
+}
+
+/// Whether a symbol is a short name, e. g. `μ` or its ASCII spelling `u`, or a full name
+fn matches(symbol: &str, (short, long): (&str, &str), full: bool) -> bool {
+    if full {
+        symbol == long
+    } else {
+        symbol == short || symbol == ascii(short)
+    }
+}
+
+/// Root or derived unit with special name without prefix, e. g. `m` or `N`
+fn unprefixed(symbol: &str, full: bool) -> Option<Term> {
+    let root = ROOTS.iter().find(|(short, long, ..)| matches(symbol, (short, long), full));
+    if let Some(&(_, _, slot, factor)) = root {
+        let mut exps = [0; SLOTS];
+        exps[slot] = 1;
+        return Some(Term { exps, factor: factor() });
+    }
+    let (.., named) = NAMED.iter().find(|(short, long, _)| matches(symbol, (short, long), full))?;
+    let mut exps = [0; SLOTS];
+    exps.iter_mut().zip(named).for_each(|(exp, named)| *exp = *named);
+    // derived units are coherent with kilogram, while mass is converted via gram
+    let ((num, den), kg) = (<Kg as Factor<f64>>::factor(), i32::from(exps[1]));
+    Some(Term { exps, factor: (num.powi(kg), den.powi(kg)) })
+}
+
+/// Unprefixed symbols first, so that e. g. `cd` is candela, not centiday
+fn symbol(symbol: &str) -> Result<Term, ParseQuantityError> {
+    let bare = unprefixed(symbol, false).or_else(|| unprefixed(symbol, true));
+    let prefixed = || {
+        PREFIXES.iter().find_map(|&(short, long, factor)| {
+            let short = [short, ascii(short)].into_iter().find_map(|short| {
+                symbol.strip_prefix(short).and_then(|rest| unprefixed(rest, false))
+            });
+            let long = || symbol.strip_prefix(long).and_then(|rest| unprefixed(rest, true));
+            let term = short.or_else(long)?;
+            Some(Term { factor: (term.factor.0 * factor.0, term.factor.1 * factor.1), ..term })
+        })
+    };
+    bare.or_else(prefixed).ok_or_else(|| ParseQuantityError::UnknownSymbol(symbol.into()))
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Symbol(String),
+    Mul,
+    Div,
+    Open,
+    Close,
+    Pow(i8),
+}
+
+fn superscript_digit(c: char) -> Option<char> {
+    "⁰¹²³⁴⁵⁶⁷⁸⁹".chars().position(|digit| digit == c).map(|digit| (b'0' + digit as u8) as char)
+}
+
+/// Exponent from its sign & ASCII digits, which must fit into `i8` like the exponents of `Unit`
+fn exponent(negative: bool, digits: &str) -> Result<Token, ParseQuantityError> {
+    if digits.is_empty() {
+        return Err(ParseQuantityError::InvalidUnit);
+    }
+    let sign = if negative { "-" } else { "" };
+    let exp = format!("{sign}{digits}").parse();
+    exp.map(Token::Pow).map_err(|_| ParseQuantityError::ExponentOutOfRange)
+}
+
+fn is_delimiter(c: char) -> bool {
+    c.is_whitespace() || "*⋅·/()^⁻".contains(c) || superscript_digit(c).is_some()
+}
+
+fn tokenize(unit: &str) -> Result<Vec<Token>, ParseQuantityError> {
+    let mut tokens = Vec::new();
+    let mut chars = unit.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '*' | '⋅' | '·' => {
+                chars.next();
+                tokens.push(Token::Mul);
+            }
+            '/' => {
+                chars.next();
+                tokens.push(Token::Div);
+            }
+            '(' => {
+                chars.next();
+                tokens.push(Token::Open);
+            }
+            ')' => {
+                chars.next();
+                tokens.push(Token::Close);
+            }
+            '^' => {
+                chars.next();
+                let negative = chars.next_if(|&c| c == '-' || c == '⁻').is_some();
+                let mut digits = String::new();
+                while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+                    digits.push(digit);
+                }
+                tokens.push(exponent(negative, &digits)?);
+            }
+            c if c == '⁻' || superscript_digit(c).is_some() => {
+                let negative = chars.next_if_eq(&'⁻').is_some();
+                let mut digits = String::new();
+                while let Some(digit) = chars.peek().copied().and_then(superscript_digit) {
+                    chars.next();
+                    digits.push(digit);
+                }
+                tokens.push(exponent(negative, &digits)?);
+            }
+            _ => {
+                let mut symbol = String::new();
+                while let Some(c) = chars.next_if(|&c| !is_delimiter(c)) {
+                    symbol.push(c);
+                }
+                tokens.push(Token::Symbol(symbol));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+type Tokens = Peekable<std::vec::IntoIter<Token>>;
+
+/// Product or quotient of factors, e. g. `kg⋅m/s²`. Juxtaposition is multiplication, e. g. `N m`
+fn expression(tokens: &mut Tokens) -> Result<Term, ParseQuantityError> {
+    let mut term = factor(tokens)?;
+    loop {
+        match tokens.peek() {
+            Some(Token::Mul) => {
+                tokens.next();
+                term = term.mul(factor(tokens)?)?;
+            }
+            Some(Token::Div) => {
+                tokens.next();
+                term = term.div(factor(tokens)?)?;
+            }
+            Some(Token::Symbol(_) | Token::Open) => term = term.mul(factor(tokens)?)?,
+            _ => return Ok(term),
+        }
+    }
+}
+
+/// Symbol or parenthesized expression with optional exponent, e. g. `s²` or `(kg⋅K)`
+fn factor(tokens: &mut Tokens) -> Result<Term, ParseQuantityError> {
+    let base = match tokens.next() {
+        Some(Token::Symbol(name)) => symbol(&name)?,
+        Some(Token::Open) => {
+            let term = expression(tokens)?;
+            if tokens.next() != Some(Token::Close) {
+                return Err(ParseQuantityError::InvalidUnit);
+            }
+            term
+        }
+        _ => return Err(ParseQuantityError::InvalidUnit),
+    };
+    match tokens.next_if(|token| matches!(token, Token::Pow(_))) {
+        Some(Token::Pow(exp)) => base.pow(exp),
+        _ => Ok(base),
+    }
+}
+
+fn parse_unit(unit: &str) -> Result<Term, ParseQuantityError> {
+    let mut tokens = tokenize(unit)?.into_iter().peekable();
+    if tokens.peek().is_none() {
+        return Ok(Term::ONE);
+    }
+    let term = expression(&mut tokens)?;
+    match tokens.next() {
+        Some(_) => Err(ParseQuantityError::InvalidUnit),
+        None => Ok(term),
+    }
+}
+
+/// Splits `"9.81m/s"` into `"9.81"` & `"m/s"`, `e` is an exponent only if followed by digits
+fn split_number(s: &str) -> (&str, &str) {
+    let bytes = s.as_bytes();
+    let digit_at = |i: usize| bytes.get(i).is_some_and(u8::is_ascii_digit);
+    let mut end = 0;
+    while end < bytes.len() {
+        let accepted = match bytes[end] {
+            b'0'..=b'9' | b'.' => true,
+            b'+' | b'-' => end == 0 || matches!(bytes[end - 1], b'e' | b'E'),
+            b'e' | b'E' => {
+                end > 0
+                    && (digit_at(end + 1)
+                        || matches!(bytes.get(end + 1), Some(b'+' | b'-')) && digit_at(end + 2))
+            }
+            _ => false,
+        };
+        if !accepted {
+            break;
+        }
+        end += 1;
+    }
+    s.split_at(end)
+}
+
+/// E. g. `"36 km/h".parse::<Quantity<MeterPerSecond, f64>>()` is `10 m/s`
+impl<L, M, Ti, I, Te, N, J, An, R, Inf, V> FromStr
+    for Quantity<Unit<L, M, Ti, I, Te, N, J, An, R, Inf>, V>
+where
+    L: SlotFactor,
+    M: SlotFactor,
+    Ti: SlotFactor,
+    I: SlotFactor,
+    Te: SlotFactor,
+    N: SlotFactor,
+    J: SlotFactor,
+    An: SlotFactor,
+    R: SlotFactor,
+    Inf: SlotFactor,
+    V: FromStr + FromF64 + Rescale,
+{
+    type Err = ParseQuantityError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (number, unit) = split_number(s.trim());
+        let value: V = number.parse().map_err(|_| ParseQuantityError::InvalidNumber)?;
+        let parsed = parse_unit(unit)?;
+
+        let mut target = (1.0, 1.0);
+        let slots = Unit::<L, M, Ti, I, Te, N, J, An, R, Inf>::slots();
+        for (slot, (((exp, den), (num, factor_den)), parsed)) in
+            slots.into_iter().zip(parsed.exps).enumerate()
+        {
+            if slot != RATIO && (den != 1 || exp != parsed) {
+                return Err(ParseQuantityError::DimensionMismatch);
+            }
+            target = (target.0 * num, target.1 * factor_den);
+        }
+
+        let factor = |(num, den): (f64, f64)| {
+            V::from_f64(num).zip(V::from_f64(den)).ok_or(ParseQuantityError::UnsupportedConversion)
+        };
+        let value = value.checked_rescale(factor(parsed.factor)?, factor(target)?);
+        value.map(Self::new).ok_or(ParseQuantityError::Overflow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParseQuantityError;
+    use crate::{
+        iec_80000,
+        isq::{
+            consts::{deg, kg, m, ohm, pct, rad, s, J, K, N},
+            unit::{
+                Degree, Dimensionless, JoulePerKilogramKelvin, Kilogram, Meter, MeterPerSecond,
+                MeterPerSecondSquared, MeterSquared, Newton, Percent, Radian,
+            },
+        },
+        Quantity,
+    };
+
+    #[test]
+    fn parse_quantities() {
+        let g: Quantity<MeterPerSecondSquared, f64> = "9.81 m/s²".parse().unwrap();
+        assert_eq!(g, 9.81 * (m / (s * s)));
+        assert_eq!("9.81 m/s^2".parse(), Ok(g));
+        assert_eq!("9.81 m⋅s⁻²".parse(), Ok(g));
+        assert_eq!("9.81m*s^-2".parse(), Ok(g));
+        assert_eq!("36 km/h".parse(), Ok(10_f64 * (m / s)));
+        assert_eq!("36 km/h".parse(), Ok(10_i32 * (m / s)));
+        assert_eq!("2 kN".parse(), Ok(2000_f64 * N));
+        assert_eq!("2 kg m/s²".parse(), Ok(2_f64 * N));
+        assert_eq!("1.5e3 g".parse(), Ok(1.5 * kg));
+        assert_eq!("500 g".parse::<Quantity<Kilogram, f64>>(), Ok(0.5 * kg));
+        assert_eq!("4 J/(kg⋅K)".parse(), Ok(4_f64 * (J / (kg * K))));
+        assert_eq!(
+            "4 (m²)/(s²⋅K)".parse::<Quantity<JoulePerKilogramKelvin, f64>>(),
+            Ok(4_f64 * (J / (kg * K)))
+        );
+        assert_eq!("3 kilometer".parse(), Ok(3000_f64 * m));
+        assert_eq!("2 ft".parse::<Quantity<Meter, f64>>(), Ok(0.6096 * m));
+        assert_eq!("180 °".parse::<Quantity<Radian, f64>>(), Ok(core::f64::consts::PI * rad));
+        assert_eq!("90 deg".parse(), Ok(90_f64 * deg));
+        assert_eq!(
+            "90 degC".parse::<Quantity<Degree, f64>>(),
+            Err(ParseQuantityError::DimensionMismatch)
+        );
+        assert_eq!("5 um".parse(), Ok(5e-6 * m));
+        assert_eq!("2 kilonewton".parse(), Ok(2000_f64 * N));
+        assert_eq!("2 kOhm".parse(), Ok(2000_f64 * ohm));
+        assert_eq!("90 degree".parse(), Ok(90_f64 * deg));
+        assert_eq!("50 %".parse(), Ok(Quantity::<Dimensionless, f64>::new(0.5)));
+        assert_eq!("0.5".parse(), Ok(Quantity::<Percent, f64>::new(50.0)));
+        assert_eq!("12 %".parse(), Ok(12_i32 * pct));
+        assert_eq!("1 MB/s".parse(), Ok(8_000_000_u64 * (iec_80000::consts::bit / s)));
+    }
+
+    fn unknown(symbol: &str) -> ParseQuantityError {
+        ParseQuantityError::UnknownSymbol(symbol.into())
+    }
+
+    #[test]
+    fn parse_errors() {
+        type Speed = Quantity<MeterPerSecond, f64>;
+
+        assert_eq!("m/s".parse::<Speed>(), Err(ParseQuantityError::InvalidNumber));
+        assert_eq!("1.2.3 m/s".parse::<Speed>(), Err(ParseQuantityError::InvalidNumber));
+        assert_eq!(
+            "1.5 m/s".parse::<Quantity<MeterPerSecond, i32>>(),
+            Err(ParseQuantityError::InvalidNumber)
+        );
+        assert_eq!("3 m/(s".parse::<Speed>(), Err(ParseQuantityError::InvalidUnit));
+        assert_eq!("3 m/s^".parse::<Speed>(), Err(ParseQuantityError::InvalidUnit));
+        assert_eq!("3 m)/s".parse::<Speed>(), Err(ParseQuantityError::InvalidUnit));
+        assert_eq!("3 parsec/s".parse::<Speed>(), Err(unknown("parsec")));
+        assert_eq!("3 m/s²".parse::<Speed>(), Err(ParseQuantityError::DimensionMismatch));
+        assert_eq!("3 N".parse::<Quantity<Newton, f64>>(), Ok(3_f64 * N));
+        assert_eq!("3".parse::<Speed>(), Err(ParseQuantityError::DimensionMismatch));
+        assert_eq!(
+            "90 °".parse::<Quantity<Radian, i32>>(),
+            Err(ParseQuantityError::UnsupportedConversion)
+        );
+        assert_eq!("3000000 km".parse::<Quantity<Meter, i32>>(), Err(ParseQuantityError::Overflow));
+        assert_eq!("3000 km".parse(), Ok(3_000_000_i32 * m));
+    }
+
+    #[test]
+    fn exponents_out_of_range() {
+        type Area = Quantity<MeterSquared, f64>;
+        let out_of_range = Err(ParseQuantityError::ExponentOutOfRange);
+
+        assert_eq!("1 m^2000000000".parse::<Area>(), out_of_range);
+        assert_eq!("1 m^99999999999999999999".parse::<Area>(), out_of_range);
+        assert_eq!("1 m²⁰⁰".parse::<Area>(), out_of_range);
+        assert_eq!("1 (m^100)^2".parse::<Area>(), out_of_range);
+        assert_eq!("1 m^100 m^100".parse::<Area>(), out_of_range);
+        assert_eq!("1 m^-128 / m".parse::<Area>(), out_of_range);
+        assert_eq!("1 s/s^-128".parse::<Area>(), out_of_range);
+        assert_eq!("1 m^127 m^-125".parse::<Area>(), Ok(1.0 * (m * m)));
+        assert_eq!("1 m⁻¹²⁸ m¹²⁷ m³".parse::<Area>(), Ok(1.0 * (m * m)));
+    }
+}
@@ -0,0 +1,324 @@
+//! Absolute temperatures, as opposed to temperature intervals
+//!
+//! `Quantity<unit::Celsius, V>` is a temperature difference, so it's converted to Kelvins
+//! without the offset. `Temperature<Celsius, V>` is a point on the Celsius scale,
+//! so the offset applies.
+
+use super::{
+    root::{ampere, meter, second, Celsius, Fahrenheit, Kelvin, Rankine},
+    Kg, Unit,
+};
+use crate::{
+    util::{format_value, pad},
+    Const, Quantity,
+};
+use const_default::ConstDefault;
+use core::{
+    cmp::Ordering,
+    convert::Infallible,
+    fmt::{self, Debug, Display, Formatter},
+    marker::PhantomData,
+    ops::{Add, Sub},
+};
+
+/// Temperature scale, `value` degrees of which are `(value + OFFSET) * DEGREE` Kelvins
+pub trait TemperatureScale {
+    /// Absolute zero in hundredths of degree, e. g. `27315` for `Celsius`
+    const OFFSET: i128;
+    /// Size of degree in Kelvins as a fraction, e. g. `(5, 9)` for `Fahrenheit`
+    const DEGREE: (i128, i128);
+}
+
+macro_rules! temperature_scales {
+    ($(($scale:ident, $offset:literal, $num:literal / $den:literal),)+) => {
+        $(impl TemperatureScale for $scale {
+            const OFFSET: i128 = $offset;
+            const DEGREE: (i128, i128) = ($num, $den);
+        })+
+    };
+}
+
+#[rustfmt::skip]
+temperature_scales! {
+    (Kelvin,         0, 1 / 1),
+    (Celsius,    27315, 1 / 1),
+    (Fahrenheit, 45967, 5 / 9),
+    (Rankine,        0, 5 / 9),
+}
+
+/// Temperature doesn't fit into integer storage type, e. g. `0 K` in unsigned Celsius
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TemperatureOutOfRange;
+
+impl Display for TemperatureOutOfRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "temperature doesn't fit into storage type")
+    }
+}
+
+impl std::error::Error for TemperatureOutOfRange {}
+
+/// Storage types which can be converted between temperature scales
+pub trait ConvertTemperature: Sized {
+    /// `Infallible` for floats, `TemperatureOutOfRange` for integers
+    type Error;
+
+    fn convert_temperature<Tl: TemperatureScale, Tr: TemperatureScale>(
+        self,
+    ) -> Result<Self, Self::Error>;
+}
+
+macro_rules! impl_convert_temperature_for_int {
+    ($($type:ident),+) => {
+        $(impl ConvertTemperature for $type {
+            type Error = TemperatureOutOfRange;
+
+            fn convert_temperature<Tl: TemperatureScale, Tr: TemperatureScale>(
+                self,
+            ) -> Result<Self, Self::Error> {
+                let ((nl, dl), (nr, dr)) = (Tl::DEGREE, Tr::DEGREE);
+                // hundredths of degree, so that offsets are exact
+                let hundredths = i128::try_from(self)
+                    .ok()
+                    .and_then(|value| value.checked_mul(100)?.checked_add(Tl::OFFSET))
+                    .and_then(|hundredths| hundredths.checked_mul(nl * dr))
+                    .map(|hundredths| hundredths / (dl * nr) - Tr::OFFSET);
+                hundredths
+                    .and_then(|hundredths| Self::try_from(hundredths / 100).ok())
+                    .ok_or(TemperatureOutOfRange)
+            }
+        })+
+    };
+}
+
+impl_convert_temperature_for_int!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize);
+
+macro_rules! impl_convert_temperature_for_float {
+    ($($type:ident),+) => {
+        $(impl ConvertTemperature for $type {
+            type Error = Infallible;
+
+            fn convert_temperature<Tl: TemperatureScale, Tr: TemperatureScale>(
+                self,
+            ) -> Result<Self, Self::Error> {
+                let ((nl, dl), (nr, dr)) = (Tl::DEGREE, Tr::DEGREE);
+                // hundredths of degree, so that offsets are exact
+                let hundredths = (self * 100.0 + Tl::OFFSET as Self) * (nl * dr) as Self
+                    / (dl * nr) as Self
+                    - Tr::OFFSET as Self;
+                Ok(hundredths / 100.0)
+            }
+        })+
+    };
+}
+
+impl_convert_temperature_for_float!(f32, f64);
+
+/// Temperature interval on scale `T`, e. g. `Interval<Celsius>` is `unit::Celsius`
+pub type Interval<T> =
+    Unit<(meter, Const<0>), (Kg, Const<0>), (second, Const<0>), (ampere, Const<0>), (T, Const<1>)>;
+
+/// Absolute temperature on scale `T`, e. g. `Kelvin` or `Celsius`
+pub struct Temperature<T, V> {
+    pub value: V,
+    phantom: PhantomData<T>,
+}
+
+impl<T, V> Temperature<T, V> {
+    #[must_use]
+    pub const fn new(value: V) -> Self {
+        Self { value, phantom: PhantomData }
+    }
+}
+
+/// Conversions of float temperatures, which can't fail
+impl<T: TemperatureScale, V: ConvertTemperature<Error = Infallible>> Temperature<T, V> {
+    pub fn from<Tother: TemperatureScale>(other: Temperature<Tother, V>) -> Self {
+        Self::try_convert_from(other).unwrap_or_else(|never| match never {})
+    }
+
+    pub fn into<Tother: TemperatureScale>(self) -> Temperature<Tother, V> {
+        self.try_convert_into().unwrap_or_else(|never| match never {})
+    }
+}
+
+/// Conversions of integer temperatures, which fail, if the result doesn't fit into `V`,
+/// e. g. `0 K` in `u32` Celsius. Named unlike `TryFrom` & `TryInto`, which aren't implemented,
+/// since their blanket implementations conflict with generic scales
+impl<T: TemperatureScale, V: ConvertTemperature> Temperature<T, V> {
+    pub fn try_convert_from<Tother: TemperatureScale>(
+        other: Temperature<Tother, V>,
+    ) -> Result<Self, V::Error> {
+        other.value.convert_temperature::<Tother, T>().map(Self::new)
+    }
+
+    pub fn try_convert_into<Tother: TemperatureScale>(
+        self,
+    ) -> Result<Temperature<Tother, V>, V::Error> {
+        self.value.convert_temperature::<T, Tother>().map(Temperature::new)
+    }
+}
+
+impl<T, V: Clone> Clone for Temperature<T, V> {
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<T, V: Copy> Copy for Temperature<T, V> {}
+
+impl<T, V: PartialEq> PartialEq for Temperature<T, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T, V: PartialOrd> PartialOrd for Temperature<T, V> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+/// Point - point = interval
+impl<T, V: Sub<Output = V>> Sub for Temperature<T, V> {
+    type Output = Quantity<Interval<T>, V>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::Output::new(self.value - rhs.value)
+    }
+}
+
+/// Point + interval = point
+impl<T, V: Add<Output = V>> Add<Quantity<Interval<T>, V>> for Temperature<T, V> {
+    type Output = Self;
+
+    fn add(self, rhs: Quantity<Interval<T>, V>) -> Self::Output {
+        Self::Output::new(self.value + rhs.value)
+    }
+}
+
+/// Interval + point = point
+impl<T, V: Add<Output = V>> Add<Temperature<T, V>> for Quantity<Interval<T>, V> {
+    type Output = Temperature<T, V>;
+
+    fn add(self, rhs: Temperature<T, V>) -> Self::Output {
+        Self::Output::new(self.value + rhs.value)
+    }
+}
+
+/// Point - interval = point
+impl<T, V: Sub<Output = V>> Sub<Quantity<Interval<T>, V>> for Temperature<T, V> {
+    type Output = Self;
+
+    fn sub(self, rhs: Quantity<Interval<T>, V>) -> Self::Output {
+        Self::Output::new(self.value - rhs.value)
+    }
+}
+
+impl<T: Debug + ConstDefault, V: Debug> Debug for Temperature<T, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        pad(f, &format!("{} {:?}", format_value!(f, &self.value, "?"), T::DEFAULT))
+    }
+}
+
+impl<T: Display + ConstDefault, V: Display> Display for Temperature<T, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        pad(f, &format!("{} {}", format_value!(f, &self.value, ""), T::DEFAULT))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Temperature, TemperatureOutOfRange, TemperatureScale};
+    use crate::{
+        isq::{
+            consts::{degC, degF, K},
+            root::{Celsius, Fahrenheit, Kelvin, Rankine},
+            unit,
+        },
+        Quantity,
+    };
+
+    #[test]
+    fn convert_absolute_temperatures() {
+        let boiling = Temperature::<Celsius, f64>::new(100.0);
+        assert_eq!(boiling.into::<Kelvin>(), Temperature::new(373.15));
+        assert_eq!(
+            Temperature::<Celsius, f64>::from(Temperature::<Fahrenheit, _>::new(212.0)),
+            boiling
+        );
+        assert_eq!(
+            Temperature::<Fahrenheit, f32>::from(Temperature::<Kelvin, _>::new(0.0)),
+            Temperature::new(-459.67)
+        );
+        assert_eq!(
+            Temperature::<Rankine, f64>::from(Temperature::<Fahrenheit, _>::new(491.67)),
+            Temperature::new(951.34)
+        );
+        assert_eq!(
+            Temperature::<Kelvin, i32>::try_convert_from(Temperature::<Celsius, _>::new(0)),
+            Ok(Temperature::new(273))
+        );
+        assert_eq!(
+            Temperature::<Celsius, i64>::try_convert_from(Temperature::<Fahrenheit, _>::new(100)),
+            Ok(Temperature::new(37))
+        );
+    }
+
+    #[test]
+    fn round_trip_between_scales() {
+        fn round_trip<Tl: TemperatureScale, Tr: TemperatureScale>(value: f64) {
+            let back = Temperature::<Tl, f64>::new(value).into::<Tr>().into::<Tl>();
+            assert!((back.value - value).abs() < 1e-9, "{value} became {}", back.value);
+        }
+
+        round_trip::<Kelvin, Celsius>(310.15);
+        round_trip::<Kelvin, Fahrenheit>(0.0);
+        round_trip::<Kelvin, Rankine>(273.15);
+        round_trip::<Celsius, Fahrenheit>(-40.0);
+        round_trip::<Celsius, Rankine>(36.6);
+        round_trip::<Fahrenheit, Rankine>(212.0);
+        assert_eq!(
+            Temperature::<Rankine, u16>::try_convert_from(Temperature::<Kelvin, _>::new(100)),
+            Ok(Temperature::new(180))
+        );
+        assert_eq!(
+            Temperature::<Rankine, u16>::new(180).try_convert_into::<Kelvin>(),
+            Ok(Temperature::new(100))
+        );
+    }
+
+    #[test]
+    fn integer_temperatures_out_of_range() {
+        assert_eq!(
+            Temperature::<Kelvin, u16>::try_convert_from(Temperature::<Celsius, _>::new(65500)),
+            Err(TemperatureOutOfRange)
+        );
+        assert_eq!(
+            Temperature::<Celsius, u32>::try_convert_from(Temperature::<Kelvin, _>::new(0)),
+            Err(TemperatureOutOfRange)
+        );
+        assert_eq!(
+            Temperature::<Celsius, u128>::new(u128::MAX).try_convert_into::<Kelvin>(),
+            Err(TemperatureOutOfRange)
+        );
+        assert_eq!(
+            Temperature::<Kelvin, u128>::new(300).try_convert_into::<Celsius>(),
+            Ok(Temperature::new(26))
+        );
+    }
+
+    #[test]
+    fn points_and_intervals() {
+        let morning = Temperature::<Celsius, f64>::new(12.0);
+        let noon = Temperature::<Celsius, f64>::new(17.0);
+        let warming = noon - morning;
+        assert_eq!(warming, 5.0 * degC);
+        assert_eq!(morning + warming, noon);
+        assert_eq!(warming + morning, noon);
+        assert_eq!(noon - warming, morning);
+        // intervals are converted without the offset
+        assert_eq!(Quantity::<unit::Kelvin, f64>::from(warming), 5.0 * K);
+        assert_eq!(Quantity::<unit::Celsius, f64>::from(9.0 * degF), 5.0 * degC);
+    }
+}
@@ -9,3 +9,4 @@ pub trait AmountOfSubstance: BaseUnit {}
 pub trait LuminousIntensity: BaseUnit {}
 pub trait Angle: BaseUnit {}
 pub trait Ratio: BaseUnit {}
+pub trait Information: BaseUnit {}
@@ -1,8 +1,10 @@
 pub mod base_unit;
 pub mod iec_80000;
+pub mod information;
 pub mod isq;
 pub mod kind;
 pub mod name;
+pub mod notation;
 pub mod ops;
 pub mod prefix;
 pub mod quantity;
@@ -1,3 +1,5 @@
+use crate::notation::Notation;
+
 /// Short & full names
 pub trait Name {
     /// Short name
@@ -6,34 +8,134 @@ pub trait Name {
     const FULL: &'static str;
 }
 
-pub trait Display {
-    fn display() -> String;
+/// Short & full names of a unit's prefix & root, from which every notation is derived
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnitName {
+    /// E. g. `("k", "kilo")`
+    pub prefix: Option<(&'static str, &'static str)>,
+    /// E. g. `("m", "meter")`
+    pub root: (&'static str, &'static str),
 }
 
-impl Display for () {
-    fn display() -> String {
-        String::new()
+impl UnitName {
+    #[must_use]
+    pub const fn root<R: Name>() -> Self {
+        Self { prefix: None, root: (R::SHORT, R::FULL) }
+    }
+
+    #[must_use]
+    pub const fn prefixed<P: Name, R: Name>() -> Self {
+        Self { prefix: Some((P::SHORT, P::FULL)), root: (R::SHORT, R::FULL) }
     }
+
+    /// E. g. `μm`, `um`, `micrometer` or `\micro\metre`
+    #[must_use]
+    pub fn render(&self, notation: Notation) -> String {
+        let (prefix_short, prefix_full) = self.prefix.unwrap_or_default();
+        let (short, full) = self.root;
+        match notation {
+            Notation::Unicode => format!("{prefix_short}{short}"),
+            Notation::Ascii => format!("{}{}", ascii(prefix_short), ascii(short)),
+            Notation::Long => format!("{prefix_full}{full}"),
+            Notation::Latex => {
+                let prefix = self.prefix.map(|(_, full)| format!("\\{full}")).unwrap_or_default();
+                format!("{prefix}{}", latex(short, full))
+            }
+        }
+    }
+}
+
+/// Name of a base unit, e. g. of a root, a prefixed root or a slot of `Unit`
+pub trait Named {
+    fn unit_name() -> UnitName;
 }
 
-impl<N: Name> Display for N {
-    fn display() -> String {
-        N::SHORT.to_string()
+impl Named for () {
+    fn unit_name() -> UnitName {
+        UnitName { prefix: None, root: ("", "") }
     }
 }
 
-pub trait Debug {
-    fn debug() -> String;
+impl<N: Name> Named for N {
+    fn unit_name() -> UnitName {
+        UnitName::root::<N>()
+    }
 }
 
-impl<N: Name> Debug for N {
-    fn debug() -> String {
-        N::FULL.to_string()
+/// ASCII spelling of a symbol, e. g. `u` for `μ` or `Ohm` for `Ω`
+#[must_use]
+pub fn ascii(symbol: &str) -> &str {
+    match symbol {
+        "μ" => "u",
+        "Ω" => "Ohm",
+        "°" => "deg",
+        "°C" => "degC",
+        "°F" => "degF",
+        "°R" => "degR",
+        "′" => "arcmin",
+        "″" => "arcsec",
+        "‰" => "permille",
+        symbol => symbol,
     }
 }
 
-pub fn superscript(num: i8) -> String {
-    let s = num.to_string();
+/// Units, which `siunitx` provides macros for
+const SIUNITX: &[&str] = &[
+    "metre",
+    "gram",
+    "second",
+    "minute",
+    "hour",
+    "day",
+    "ampere",
+    "kelvin",
+    "degreeCelsius",
+    "mole",
+    "candela",
+    "radian",
+    "degree",
+    "arcminute",
+    "arcsecond",
+    "steradian",
+    "hertz",
+    "newton",
+    "pascal",
+    "joule",
+    "watt",
+    "coulomb",
+    "volt",
+    "farad",
+    "ohm",
+    "siemens",
+    "weber",
+    "tesla",
+    "henry",
+    "lumen",
+    "lux",
+    "katal",
+    "percent",
+    "astronomicalunit",
+    "bit",
+    "byte",
+];
+
+/// `siunitx` macro of a root, e. g. `\metre` or `\degreeCelsius`, or its symbol as text
+fn latex(short: &str, full: &str) -> String {
+    let mut words = full.split(' ');
+    let mut name = words.next().unwrap_or_default().to_lowercase();
+    name.extend(words);
+    if name == "meter" {
+        name = "metre".to_string();
+    }
+    if SIUNITX.contains(&name.as_str()) {
+        format!("\\{name}")
+    } else {
+        format!("\\text{{{short}}}")
+    }
+}
+
+pub fn superscript(num: impl Into<i32>) -> String {
+    let s = num.into().to_string();
     s.bytes()
         .map(|c| match c {
             b'0' => '⁰',
@@ -51,3 +153,51 @@ pub fn superscript(num: i8) -> String {
         })
         .collect()
 }
+
+pub fn subscript(num: i8) -> String {
+    let s = num.to_string();
+    s.bytes()
+        .map(|c| match c {
+            b'0' => '₀',
+            b'1' => '₁',
+            b'2' => '₂',
+            b'3' => '₃',
+            b'4' => '₄',
+            b'5' => '₅',
+            b'6' => '₆',
+            b'7' => '₇',
+            b'8' => '₈',
+            b'9' => '₉',
+            b'-' => '₋',
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+/// Fractional exponent, e. g. `½` or `⁵⁄₂`
+pub fn fraction(num: impl Into<i32>, den: i8) -> String {
+    let num = num.into();
+    let vulgar = match (num.abs(), den) {
+        (1, 2) => "½",
+        (1, 3) => "⅓",
+        (2, 3) => "⅔",
+        (1, 4) => "¼",
+        (3, 4) => "¾",
+        (1, 5) => "⅕",
+        (2, 5) => "⅖",
+        (3, 5) => "⅗",
+        (4, 5) => "⅘",
+        (1, 6) => "⅙",
+        (5, 6) => "⅚",
+        (1, 8) => "⅛",
+        (3, 8) => "⅜",
+        (5, 8) => "⅝",
+        (7, 8) => "⅞",
+        _ => return format!("{}⁄{}", superscript(num), subscript(den)),
+    };
+    if num < 0 {
+        format!("⁻{vulgar}")
+    } else {
+        vulgar.to_string()
+    }
+}
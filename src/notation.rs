@@ -0,0 +1,131 @@
+//! Alternative notations of units & quantities, e. g. ASCII for logs or LaTeX for documents
+
+use crate::{
+    name::superscript,
+    util::{format_value, pad, with_unit},
+    Quantity,
+};
+use core::{
+    fmt::{self, Display, Formatter, LowerExp},
+    marker::PhantomData,
+};
+
+/// Notation of units
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Notation {
+    /// `m⋅kg/s²`, which `Display` uses
+    #[default]
+    Unicode,
+    /// `m*kg/s^2`
+    Ascii,
+    /// `meter kilogram per second squared`
+    Long,
+    /// `\si{\metre\kilo\gram\per\second\squared}` for `siunitx`
+    Latex,
+}
+
+/// Notation of units & whether values are written in scientific notation
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Style {
+    pub notation: Notation,
+    /// `1.2×10⁻³`, or `1.2e-3` in ASCII & LaTeX
+    pub scientific: bool,
+}
+
+impl Style {
+    #[must_use]
+    pub const fn new(notation: Notation) -> Self {
+        Self { notation, scientific: false }
+    }
+
+    /// Values in scientific notation, e. g. `Style::new(Notation::Ascii).scientific()`
+    #[must_use]
+    pub const fn scientific(self) -> Self {
+        Self { scientific: true, ..self }
+    }
+}
+
+impl From<Notation> for Style {
+    fn from(notation: Notation) -> Self {
+        Self::new(notation)
+    }
+}
+
+/// Unit, which can be written in any notation
+pub trait FormatUnit {
+    /// `named` is whether derived units with special names are used, e. g. `N` for `m⋅kg/s²`
+    fn fmt_unit(f: &mut Formatter<'_>, notation: Notation, named: bool) -> fmt::Result;
+}
+
+/// Unit or quantity in a particular style, e. g. `(9.81 * (m / (s * s))).format_with(Notation::Ascii)`
+///
+/// Like `Display`, `{:#}` writes SI base units only, e. g. `m*kg/s^2` instead of `N`.
+#[derive(Clone, Copy, Debug)]
+pub struct Formatted<T> {
+    inner: T,
+    style: Style,
+}
+
+impl<T> Formatted<T> {
+    pub(crate) fn new(inner: T, style: impl Into<Style>) -> Self {
+        Self { inner, style: style.into() }
+    }
+}
+
+/// Unit in a notation as `Display`, so that it's padded together with the value
+struct InNotation<U> {
+    notation: Notation,
+    named: bool,
+    unit: PhantomData<U>,
+}
+
+impl<U: FormatUnit> Display for InNotation<U> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        U::fmt_unit(f, self.notation, self.named)
+    }
+}
+
+fn unit<U: FormatUnit>(f: &Formatter<'_>, notation: Notation) -> InNotation<U> {
+    InNotation { notation, named: !f.alternate(), unit: PhantomData }
+}
+
+impl<U: FormatUnit> Display for Formatted<U> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.style.notation {
+            Notation::Latex => pad(f, &format!("\\si{{{}}}", unit::<U>(f, Notation::Latex))),
+            notation => pad(f, &unit::<U>(f, notation).to_string()),
+        }
+    }
+}
+
+/// Like `Display` of `Quantity`, sign & precision flags apply to the value, width to the whole
+impl<U: FormatUnit, V: Display + LowerExp> Display for Formatted<Quantity<U, V>> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let value = format_value_in(f, &self.inner.value, self.style);
+        match self.style.notation {
+            Notation::Latex => {
+                pad(f, &format!("\\SI{{{value}}}{{{}}}", unit::<U>(f, Notation::Latex)))
+            }
+            notation => pad(f, &with_unit(&value, unit::<U>(f, notation))),
+        }
+    }
+}
+
+/// `1.2×10⁻³` in Unicode & long notations, `1.2e-3` otherwise
+fn format_value_in<V: Display + LowerExp>(f: &Formatter<'_>, value: &V, style: Style) -> String {
+    if !style.scientific {
+        return format_value!(f, value, "");
+    }
+    let value = format_value!(f, value, "e");
+    match style.notation {
+        Notation::Ascii | Notation::Latex => value,
+        Notation::Unicode | Notation::Long => {
+            // `NaN` & `inf` have no exponent
+            let (mantissa, exp) = value.split_once('e').unwrap_or((&value, "0"));
+            match exp.parse::<i32>().unwrap_or_default() {
+                0 => mantissa.to_string(),
+                exp => format!("{mantissa}×10{}", superscript(exp)),
+            }
+        }
+    }
+}
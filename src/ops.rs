@@ -1,4 +1,5 @@
 use crate::util::{binary_ops_out_aliases, unary_ops_out_aliases};
+use typenum::{B0, B1};
 
 unary_ops_out_aliases! {
     Inv -> Inverse,
@@ -27,6 +28,18 @@ pub trait Inv {
     type Output;
 }
 
+/// Raising to the power of `E`, e. g. `typenum::Const<3>`
+pub trait Pow<E> {
+    type Output;
+}
+
+/// Operand of multiplication or division with its roots replaced by the finer ones of both,
+/// where both have non-zero exponents, e. g. `Align<m / s>` for `km` is `m` & `Align<km>` for `m / s`
+/// is `m / s`, so that `km * (m / s)` is `m * (m / s)`
+pub trait Align<Rhs = Self> {
+    type Output;
+}
+
 pub trait One {
     const ONE: Self;
 }
@@ -36,4 +49,241 @@ binary_ops_out_aliases! {
     Sub -> Diff,
     Mul -> Prod,
     Div -> Quot,
+    Pow -> Power,
+    Align -> Aligned,
+}
+
+macro_rules! impl_one {
+    ($(($type:ident, $one:literal),)+) => {
+        $(impl One for $type {
+            const ONE: Self = $one;
+        })+
+    };
+}
+
+impl_one! {
+    (f32, 1.0),
+    (f64, 1.0),
+    (i8, 1),
+    (u8, 1),
+    (i16, 1),
+    (u16, 1),
+    (i32, 1),
+    (u32, 1),
+    (i64, 1),
+    (u64, 1),
+    (i128, 1),
+    (u128, 1),
+    (isize, 1),
+    (usize, 1),
+}
+
+/// `base^exp`
+pub fn pow<V: One + Copy + core::ops::Mul<Output = V>>(base: V, exp: u8) -> V {
+    (0..exp).fold(V::ONE, |acc, _| acc * base)
+}
+
+/// `base^exp` for the sign of `exp`, see `IsNegative`: `B0` for non-negative exponents,
+/// `B1` for negative ones, which need `Recip`, since e. g. `2_i32^-1` would truncate to `0`
+pub trait PowValue<V> {
+    fn pow(base: V, exp: i8) -> V;
+}
+
+impl<V: One + Copy + core::ops::Mul<Output = V>> PowValue<V> for B0 {
+    fn pow(base: V, exp: i8) -> V {
+        pow(base, exp.unsigned_abs())
+    }
+}
+
+impl<V: One + Copy + core::ops::Mul<Output = V> + Recip> PowValue<V> for B1 {
+    fn pow(base: V, exp: i8) -> V {
+        pow(base, exp.unsigned_abs()).recip()
+    }
+}
+
+/// Reciprocal of a storage type, only floats, since integer reciprocals truncate to `0`
+pub trait Recip {
+    fn recip(self) -> Self;
+}
+
+/// Square root of a storage type
+pub trait Sqrt {
+    fn sqrt(self) -> Self;
+}
+
+/// Cube root of a storage type
+pub trait Cbrt {
+    fn cbrt(self) -> Self;
+}
+
+macro_rules! impl_sqrt_cbrt_for_float {
+    ($($type:ident),+) => {
+        $(impl Sqrt for $type {
+            fn sqrt(self) -> Self {
+                $type::sqrt(self)
+            }
+        }
+
+        impl Cbrt for $type {
+            fn cbrt(self) -> Self {
+                $type::cbrt(self)
+            }
+        })+
+    };
+}
+
+impl_sqrt_cbrt_for_float!(f32, f64);
+
+macro_rules! impl_recip_for_float {
+    ($($type:ident),+) => {
+        $(impl Recip for $type {
+            fn recip(self) -> Self {
+                $type::recip(self)
+            }
+        })+
+    };
+}
+
+impl_recip_for_float!(f32, f64);
+
+/// Trigonometric functions of a storage type, in radians
+pub trait Trig {
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+}
+
+macro_rules! impl_trig_for_float {
+    ($($type:ident),+) => {
+        $(impl Trig for $type {
+            fn sin(self) -> Self {
+                $type::sin(self)
+            }
+
+            fn cos(self) -> Self {
+                $type::cos(self)
+            }
+
+            fn tan(self) -> Self {
+                $type::tan(self)
+            }
+
+            fn asin(self) -> Self {
+                $type::asin(self)
+            }
+
+            fn acos(self) -> Self {
+                $type::acos(self)
+            }
+
+            fn atan(self) -> Self {
+                $type::atan(self)
+            }
+
+            fn atan2(self, other: Self) -> Self {
+                $type::atan2(self, other)
+            }
+        })+
+    };
+}
+
+impl_trig_for_float!(f32, f64);
+
+/// Exponential, logarithmic & hyperbolic functions of a storage type
+pub trait Transcendental {
+    fn exp(self) -> Self;
+    fn exp2(self) -> Self;
+    fn ln(self) -> Self;
+    fn log2(self) -> Self;
+    fn log10(self) -> Self;
+    fn sinh(self) -> Self;
+    fn cosh(self) -> Self;
+    fn tanh(self) -> Self;
+}
+
+macro_rules! impl_transcendental_for_float {
+    ($($type:ident),+) => {
+        $(impl Transcendental for $type {
+            fn exp(self) -> Self {
+                $type::exp(self)
+            }
+
+            fn exp2(self) -> Self {
+                $type::exp2(self)
+            }
+
+            fn ln(self) -> Self {
+                $type::ln(self)
+            }
+
+            fn log2(self) -> Self {
+                $type::log2(self)
+            }
+
+            fn log10(self) -> Self {
+                $type::log10(self)
+            }
+
+            fn sinh(self) -> Self {
+                $type::sinh(self)
+            }
+
+            fn cosh(self) -> Self {
+                $type::cosh(self)
+            }
+
+            fn tanh(self) -> Self {
+                $type::tanh(self)
+            }
+        })+
+    };
+}
+
+impl_transcendental_for_float!(f32, f64);
+
+/// Conversion of a storage type into `f64`, which may round, e. g. for formatting
+pub trait ToF64 {
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_to_f64 {
+    ($($type:ident),+) => {
+        $(impl ToF64 for $type {
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+        })+
+    };
 }
+
+impl_to_f64!(f32, f64, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize);
+
+/// Conversion of a conversion factor into a storage type, `None` if it can't be represented,
+/// e. g. `π` in integers
+pub trait FromF64: Sized {
+    fn from_f64(value: f64) -> Option<Self>;
+}
+
+macro_rules! impl_from_f64 {
+    (@float $($type:ident),+) => {
+        $(impl FromF64 for $type {
+            fn from_f64(value: f64) -> Option<Self> {
+                Some(value as $type)
+            }
+        })+
+    };
+    ($($type:ident),+) => {
+        $(impl FromF64 for $type {
+            fn from_f64(value: f64) -> Option<Self> {
+                (value as $type as f64 == value).then_some(value as $type)
+            }
+        })+
+    };
+}
+
+impl_from_f64!(@float f32, f64);
+impl_from_f64!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize);
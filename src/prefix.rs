@@ -1,16 +1,45 @@
 use crate::Name;
 
 pub trait Prefix<V>: Name {
+    /// Base, e. g. `10` for `kilo` & `1024` for `kibi`
     const BASE: V;
-    const EXP: V;
+    /// Power of `BASE`, e. g. `3` for `kilo` & `-3` for `milli`
+    const EXP: i8;
+    /// Multiplier `BASE^EXP` as an exact fraction `(numerator, denominator)`,
+    /// e. g. `(1000, 1)` for `kilo` & `(1, 1000)` for `milli`
+    const FACTOR: (V, V);
 }
 
+/// Fails constant evaluation if prefix base doesn't fit into storage type, e. g. `1024_u8`
+pub(crate) const fn assert_base_fits(fits: bool) {
+    assert!(fits, "prefix base doesn't fit into storage type");
+}
+
+/// Constants are evaluated lazily, so e. g. `Prefix<u8>` for `kilo` is usable
+/// as long as its `FACTOR` isn't
 macro_rules! impl_prefix {
-    ($prefix:ident, $base:literal, $exp:literal, $type:ident) => {
-        impl $crate::Prefix<$type> for $prefix {
-            const BASE: $type = $base;
-            const EXP: $type = $exp;
-        }
+    ($prefix:ident, $base:literal, $exp:literal, $($type:ident),+) => {
+        $(impl $crate::Prefix<$type> for $prefix {
+            const BASE: $type = {
+                $crate::prefix::assert_base_fits($base as i128 as $type as i128 == $base);
+                $base as i128 as $type
+            };
+            const EXP: i8 = $exp;
+            const FACTOR: ($type, $type) = {
+                $crate::prefix::assert_base_fits($base as i128 as $type as i128 == $base);
+                let mut scale = 1 as $type;
+                let mut i = 0;
+                while i < (($exp) as i8).unsigned_abs() {
+                    scale *= $base as i128 as $type;
+                    i += 1;
+                }
+                if $exp >= 0 {
+                    (scale, 1 as $type)
+                } else {
+                    (1 as $type, scale)
+                }
+            };
+        })+
     };
 }
 
@@ -33,18 +62,10 @@ macro_rules! prefixes {
         }
 
         // #[cfg(feature = $full_str)]
-        // crate::prefix::impl_prefix!($full, $base, $exp, i32);
-        // crate::prefix::impl_prefix!($full, $base, $exp, u8);
-        crate::prefix::impl_prefix!($full, $base, $exp, i16);
-        // crate::prefix::impl_prefix!($full, $base, $exp, u16);
-        crate::prefix::impl_prefix!($full, $base, $exp, i32);
-        // crate::prefix::impl_prefix!($full, $base, $exp, u32);
-        crate::prefix::impl_prefix!($full, $base, $exp, i64);
-        // crate::prefix::impl_prefix!($full, $base, $exp, u64);
-        crate::prefix::impl_prefix!($full, $base, $exp, i128);
-        // crate::prefix::impl_prefix!($full, $base, $exp, u128);
-        crate::prefix::impl_prefix!($full, $base, $exp, isize);
-        // crate::prefix::impl_prefix!($full, $base, $exp, usize);
+        crate::prefix::impl_prefix!(
+            $full, $base, $exp,
+            f32, f64, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize
+        );
 
         impl const_default::ConstDefault for $full {
             const DEFAULT: Self = Self;
@@ -1,12 +1,22 @@
-use crate::base_unit::ConvertFrom;
+use crate::{
+    base_unit::{AlignValue, AlignedOp, ConvertFrom, IsNegative, Negative},
+    notation::{Formatted, Style},
+    ops::{Aligned, Cbrt, Inv, Inverse, One, Pow, PowValue, Power, Recip, Sqrt},
+    typenum::{
+        frac::{Half, Third},
+        Constant, Count, ToConst,
+    },
+    util::{format_value, pad, with_unit},
+    Const,
+};
 use const_default::ConstDefault;
 use core::{
     cmp::Ordering,
-    fmt::{self, Debug, Display, Formatter},
+    fmt::{self, Debug, Display, Formatter, LowerExp, UpperExp},
+    iter::Sum,
     marker::PhantomData,
-    ops::{Add, Div, Mul, Sub},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign},
 };
-use typenum::op;
 
 pub struct Quantity<U, V> {
     pub value: V,
@@ -21,9 +31,32 @@ impl<U, V: Clone> Clone for Quantity<U, V> {
 
 impl<U, V: Copy> Copy for Quantity<U, V> {}
 
+/// `Quantity { value, unit }` with a value, which is already formatted
+struct DebugQuantity<'a, U> {
+    value: &'a str,
+    unit: PhantomData<U>,
+}
+
+impl<U: Debug + ConstDefault> Debug for DebugQuantity<'_, U> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Quantity")
+            .field("value", &format_args!("{}", self.value))
+            .field("unit", &U::DEFAULT)
+            .finish()
+    }
+}
+
+/// E. g. `Quantity { value: 3.0, unit: Unit { L: Slot { .. }, dimension: L¹M⁰T⁰… } }`,
+/// where precision & sign flags apply to the value, width to the whole quantity
 impl<U: Debug + ConstDefault, V: Debug> Debug for Quantity<U, V> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?} {:?}", self.value, U::DEFAULT)
+        let value = format_value!(f, &self.value, "?");
+        let quantity = DebugQuantity::<U> { value: &value, unit: PhantomData };
+        if f.alternate() {
+            pad(f, &format!("{quantity:#?}"))
+        } else {
+            pad(f, &format!("{quantity:?}"))
+        }
     }
 }
 
@@ -53,6 +86,8 @@ impl<U, V> Quantity<U, V> {
         Self { value, phantom: PhantomData }
     }
 
+    /// Panics, if an integer value doesn't fit into storage type after conversion,
+    /// see `checked_from`
     pub fn from<Uother>(other: Quantity<Uother, V>) -> Self
     where
         U: ConvertFrom<Uother, V>,
@@ -60,9 +95,91 @@ impl<U, V> Quantity<U, V> {
         Self::new(U::convert_from(other.value))
     }
 
+    /// Panics, if an integer value doesn't fit into storage type after conversion,
+    /// e. g. `(200_u8 * km).into::<Meter>()`, see `checked_into`
     pub fn into<Uother: ConvertFrom<U, V>>(self) -> Quantity<Uother, V> {
         Quantity::new(Uother::convert_from(self.value))
     }
+
+    /// Like `from`, but `None` if an integer value doesn't fit into storage type
+    pub fn checked_from<Uother>(other: Quantity<Uother, V>) -> Option<Self>
+    where
+        U: ConvertFrom<Uother, V>,
+    {
+        U::checked_convert_from(other.value).map(Self::new)
+    }
+
+    /// Like `into`, but `None` if an integer value doesn't fit into storage type,
+    /// e. g. `(200_u8 * km).checked_into::<Meter>()`
+    pub fn checked_into<Uother: ConvertFrom<U, V>>(self) -> Option<Quantity<Uother, V>> {
+        Uother::checked_convert_from(self.value).map(Quantity::new)
+    }
+
+    /// E. g. `(9.81 * (m / (s * s))).format_with(Notation::Ascii)` is displayed as `9.81 m/s^2`
+    pub fn format_with(self, style: impl Into<Style>) -> Formatted<Self> {
+        Formatted::new(self, style)
+    }
+
+    /// Integer power, e. g. `side.powi::<3>()` is a volume.
+    /// Negative exponents need float storage, see `Recip`
+    pub fn powi<const E: i8>(self) -> Quantity<Power<U, Const<E>>, V>
+    where
+        U: Pow<Const<E>>,
+        Const<E>: IsNegative,
+        Negative<Const<E>>: PowValue<V>,
+    {
+        Quantity::new(Negative::<Const<E>>::pow(self.value, E))
+    }
+
+    /// Square root, e. g. `area.sqrt()` is a length
+    pub fn sqrt(self) -> Quantity<Power<U, Half>, V>
+    where
+        U: Pow<Half>,
+        V: Sqrt,
+    {
+        Quantity::new(self.value.sqrt())
+    }
+
+    /// Cube root, e. g. `volume.cbrt()` is a length
+    pub fn cbrt(self) -> Quantity<Power<U, Third>, V>
+    where
+        U: Pow<Third>,
+        V: Cbrt,
+    {
+        Quantity::new(self.value.cbrt())
+    }
+
+    /// Reciprocal, e. g. `period.recip()` is a frequency, only for float storage
+    pub fn recip(self) -> Quantity<Inverse<U>, V>
+    where
+        U: Inv,
+        V: Recip,
+    {
+        Quantity::new(self.value.recip())
+    }
+
+    /// Product of `N` factors, e. g. `Quantity::product([a, b, c])` is a volume
+    /// for lengths `a`, `b` & `c`
+    pub fn product<const N: usize>(factors: [Self; N]) -> Quantity<Power<U, Constant<Count<N>>>, V>
+    where
+        Count<N>: ToConst,
+        U: Pow<Constant<Count<N>>>,
+        V: One + Mul<Output = V>,
+    {
+        Quantity::new(factors.into_iter().fold(V::ONE, |product, factor| product * factor.value))
+    }
+}
+
+impl<U, V: Sum> Sum for Quantity<U, V> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self::new(iter.map(|quantity| quantity.value).sum())
+    }
+}
+
+impl<'a, U, V: Sum<&'a V>> Sum<&'a Quantity<U, V>> for Quantity<U, V> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        Self::new(iter.map(|quantity| &quantity.value).sum())
+    }
 }
 
 impl<U, V: Default> Default for Quantity<U, V> {
@@ -71,48 +188,287 @@ impl<U, V: Default> Default for Quantity<U, V> {
     }
 }
 
-impl<Ul: Mul<Ur>, Ur, V: Mul<Output = V>> Mul<Quantity<Ur, V>> for Quantity<Ul, V> {
-    type Output = Quantity<op!(Ul * Ur), V>;
+/// `(Changed, Changed)` of aligning `Ul` against `Ur` & `Ur` against `Ul`, see `AlignedOp`
+type Changes<Ul, Ur, V> = (<Ul as AlignValue<Ur, V>>::Changed, <Ur as AlignValue<Ul, V>>::Changed);
 
-    fn mul(self, rhs: Quantity<Ur, V>) -> Self::Output {
-        Self::Output::new(self.value * rhs.value)
-    }
+/// Implement `$op` for owned & borrowed quantities, which changes the unit, e. g. `&a * &b`.
+/// Operands are aligned against each other, see `Align`, so that `km * m` & `m * km` are both `m²`.
+/// Values are only converted, if roots are changed, so e. g. `km * km` is just `Ul * Ur`
+macro_rules! impl_unit_op_for_quantity {
+    ($(($op:ident, $fun:ident),)+) => {
+        $(impl<Ul, Ur, V: $op<Output = V>> $op<Quantity<Ur, V>> for Quantity<Ul, V>
+        where
+            Ul: AlignValue<Ur, V>,
+            Ur: AlignValue<Ul, V>,
+            Aligned<Ul, Ur>: $op<Aligned<Ur, Ul>>,
+            Changes<Ul, Ur, V>: AlignedOp<Ul, Ur, V>,
+        {
+            type Output = Quantity<<Aligned<Ul, Ur> as $op<Aligned<Ur, Ul>>>::Output, V>;
+
+            fn $fun(self, rhs: Quantity<Ur, V>) -> Self::Output {
+                let value = <Changes<Ul, Ur, V> as AlignedOp<Ul, Ur, V>>::$fun(self.value, rhs.value);
+                Self::Output::new(value)
+            }
+        }
+
+        impl<'r, Ul, Ur, V: $op<&'r V, Output = V>> $op<&'r Quantity<Ur, V>> for Quantity<Ul, V>
+        where
+            Ul: AlignValue<Ur, V>,
+            Ur: AlignValue<Ul, V>,
+            Aligned<Ul, Ur>: $op<Aligned<Ur, Ul>>,
+            Changes<Ul, Ur, V>: AlignedOp<Ul, Ur, V>,
+        {
+            type Output = Quantity<<Aligned<Ul, Ur> as $op<Aligned<Ur, Ul>>>::Output, V>;
+
+            fn $fun(self, rhs: &'r Quantity<Ur, V>) -> Self::Output {
+                let value = <Changes<Ul, Ur, V> as AlignedOp<Ul, Ur, V>>::$fun(self.value, &rhs.value);
+                Self::Output::new(value)
+            }
+        }
+
+        impl<'l, Ul, Ur, V> $op<Quantity<Ur, V>> for &'l Quantity<Ul, V>
+        where
+            Ul: AlignValue<Ur, V>,
+            Ur: AlignValue<Ul, V>,
+            Aligned<Ul, Ur>: $op<Aligned<Ur, Ul>>,
+            Changes<Ul, Ur, V>: AlignedOp<Ul, Ur, V>,
+            &'l V: $op<V, Output = V>,
+        {
+            type Output = Quantity<<Aligned<Ul, Ur> as $op<Aligned<Ur, Ul>>>::Output, V>;
+
+            fn $fun(self, rhs: Quantity<Ur, V>) -> Self::Output {
+                let value = <Changes<Ul, Ur, V> as AlignedOp<Ul, Ur, V>>::$fun(&self.value, rhs.value);
+                Self::Output::new(value)
+            }
+        }
+
+        impl<'l, 'r, Ul, Ur, V> $op<&'r Quantity<Ur, V>> for &'l Quantity<Ul, V>
+        where
+            Ul: AlignValue<Ur, V>,
+            Ur: AlignValue<Ul, V>,
+            Aligned<Ul, Ur>: $op<Aligned<Ur, Ul>>,
+            Changes<Ul, Ur, V>: AlignedOp<Ul, Ur, V>,
+            &'l V: $op<&'r V, Output = V>,
+        {
+            type Output = Quantity<<Aligned<Ul, Ur> as $op<Aligned<Ur, Ul>>>::Output, V>;
+
+            fn $fun(self, rhs: &'r Quantity<Ur, V>) -> Self::Output {
+                let value = <Changes<Ul, Ur, V> as AlignedOp<Ul, Ur, V>>::$fun(&self.value, &rhs.value);
+                Self::Output::new(value)
+            }
+        })+
+    };
 }
 
-impl<Ul: Div<Ur>, Ur, V: Div<Output = V>> Div<Quantity<Ur, V>> for Quantity<Ul, V> {
-    type Output = Quantity<op!(Ul / Ur), V>;
+impl_unit_op_for_quantity! {
+    (Mul, mul),
+    (Div, div),
+}
 
-    fn div(self, rhs: Quantity<Ur, V>) -> Self::Output {
-        Self::Output::new(self.value / rhs.value)
-    }
+/// Implement `$op` for owned & borrowed quantities of the same unit, e. g. `&a + &b`
+macro_rules! impl_same_unit_op_for_quantity {
+    ($(($op:ident, $fun:ident),)+) => {
+        $(impl<U, V: $op<Output = V>> $op for Quantity<U, V> {
+            type Output = Self;
+
+            fn $fun(self, rhs: Quantity<U, V>) -> Self::Output {
+                Self::Output::new(self.value.$fun(rhs.value))
+            }
+        }
+
+        impl<'r, U, V: $op<&'r V, Output = V>> $op<&'r Quantity<U, V>> for Quantity<U, V> {
+            type Output = Self;
+
+            fn $fun(self, rhs: &'r Quantity<U, V>) -> Self::Output {
+                Self::Output::new(self.value.$fun(&rhs.value))
+            }
+        }
+
+        impl<'l, U, V> $op<Quantity<U, V>> for &'l Quantity<U, V>
+        where
+            &'l V: $op<V, Output = V>,
+        {
+            type Output = Quantity<U, V>;
+
+            fn $fun(self, rhs: Quantity<U, V>) -> Self::Output {
+                Self::Output::new((&self.value).$fun(rhs.value))
+            }
+        }
+
+        impl<'l, 'r, U, V> $op<&'r Quantity<U, V>> for &'l Quantity<U, V>
+        where
+            &'l V: $op<&'r V, Output = V>,
+        {
+            type Output = Quantity<U, V>;
+
+            fn $fun(self, rhs: &'r Quantity<U, V>) -> Self::Output {
+                Self::Output::new((&self.value).$fun(&rhs.value))
+            }
+        })+
+    };
 }
 
-impl<U, V: Add<Output = V>> Add for Quantity<U, V> {
-    type Output = Self;
+impl_same_unit_op_for_quantity! {
+    (Add, add),
+    (Sub, sub),
+    (Rem, rem),
+}
 
-    fn add(self, rhs: Quantity<U, V>) -> Self::Output {
-        Self::Output::new(self.value + rhs.value)
+/// Implement `$op` for owned & borrowed quantities of the same unit, e. g. `a += &b`
+macro_rules! impl_same_unit_assign_op_for_quantity {
+    ($(($op:ident, $fun:ident),)+) => {
+        $(impl<U, V: $op> $op for Quantity<U, V> {
+            fn $fun(&mut self, rhs: Quantity<U, V>) {
+                self.value.$fun(rhs.value);
+            }
+        }
+
+        impl<'r, U, V: $op<&'r V>> $op<&'r Quantity<U, V>> for Quantity<U, V> {
+            fn $fun(&mut self, rhs: &'r Quantity<U, V>) {
+                self.value.$fun(&rhs.value);
+            }
+        })+
+    };
+}
+
+impl_same_unit_assign_op_for_quantity! {
+    (AddAssign, add_assign),
+    (SubAssign, sub_assign),
+}
+
+/// Scaling by a dimensionless value keeps the unit, e. g. `speed *= 2.0`
+impl<U, V: MulAssign> MulAssign<V> for Quantity<U, V> {
+    fn mul_assign(&mut self, rhs: V) {
+        self.value *= rhs;
+    }
+}
+
+impl<U, V: DivAssign> DivAssign<V> for Quantity<U, V> {
+    fn div_assign(&mut self, rhs: V) {
+        self.value /= rhs;
     }
 }
 
-impl<U, V: Sub<Output = V>> Sub for Quantity<U, V> {
+impl<U, V: Neg<Output = V>> Neg for Quantity<U, V> {
     type Output = Self;
 
-    fn sub(self, rhs: Quantity<U, V>) -> Self::Output {
-        Self::Output::new(self.value - rhs.value)
+    fn neg(self) -> Self::Output {
+        Self::Output::new(-self.value)
     }
 }
 
-impl<U: Display + ConstDefault, V: Display> Display for Quantity<U, V> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{} {}", self.value, U::DEFAULT)
+impl<'a, U, V> Neg for &'a Quantity<U, V>
+where
+    &'a V: Neg<Output = V>,
+{
+    type Output = Quantity<U, V>;
+
+    fn neg(self) -> Self::Output {
+        Self::Output::new(-&self.value)
     }
 }
 
+/// Implement scaling of `Quantity<U, $type>` by `$type` (e. g. `f32`)
+/// `2_f32 * speed`, `speed * 2_f32`, `speed / 2_f32`, `2_f32 / speed`
+macro_rules! impl_mul_div_for_quantity_by_value {
+    ($($type:ident),+) => {
+        $(impl<U> Mul<$type> for Quantity<U, $type> {
+            type Output = Self;
+
+            fn mul(self, rhs: $type) -> Self::Output {
+                Self::Output::new(self.value * rhs)
+            }
+        }
+
+        impl<U> Div<$type> for Quantity<U, $type> {
+            type Output = Self;
+
+            fn div(self, rhs: $type) -> Self::Output {
+                Self::Output::new(self.value / rhs)
+            }
+        }
+
+        impl<U> Mul<Quantity<U, $type>> for $type {
+            type Output = Quantity<U, $type>;
+
+            fn mul(self, rhs: Quantity<U, $type>) -> Self::Output {
+                Self::Output::new(self * rhs.value)
+            }
+        }
+
+        // 10 / (2 m/s) = 5 s/m
+        impl<U: Inv> Div<Quantity<U, $type>> for $type {
+            type Output = Quantity<Inverse<U>, $type>;
+
+            fn div(self, rhs: Quantity<U, $type>) -> Self::Output {
+                Self::Output::new(self / rhs.value)
+            }
+        }
+
+        impl<U> Mul<$type> for &Quantity<U, $type> {
+            type Output = Quantity<U, $type>;
+
+            fn mul(self, rhs: $type) -> Self::Output {
+                Self::Output::new(self.value * rhs)
+            }
+        }
+
+        impl<U> Div<$type> for &Quantity<U, $type> {
+            type Output = Quantity<U, $type>;
+
+            fn div(self, rhs: $type) -> Self::Output {
+                Self::Output::new(self.value / rhs)
+            }
+        }
+
+        impl<U> Mul<&Quantity<U, $type>> for $type {
+            type Output = Quantity<U, $type>;
+
+            fn mul(self, rhs: &Quantity<U, $type>) -> Self::Output {
+                Self::Output::new(self * rhs.value)
+            }
+        }
+
+        impl<U: Inv> Div<&Quantity<U, $type>> for $type {
+            type Output = Quantity<Inverse<U>, $type>;
+
+            fn div(self, rhs: &Quantity<U, $type>) -> Self::Output {
+                Self::Output::new(self / rhs.value)
+            }
+        })+
+    };
+}
+
+impl_mul_div_for_quantity_by_value!(f32, f64, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128);
+
+/// Writes `value unit` or just the value of a dimensionless quantity, e. g. `{:+.1}` applies to the value & `{:>10}` to the whole quantity
+macro_rules! impl_fmt_for_quantity {
+    ($($trait:ident, $spec:literal;)+) => {
+        $(impl<U: Display + ConstDefault, V: $trait> $trait for Quantity<U, V> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                let value = format_value!(f, &self.value, $spec);
+                if f.alternate() {
+                    pad(f, &with_unit(&value, format_args!("{:#}", U::DEFAULT)))
+                } else {
+                    pad(f, &with_unit(&value, U::DEFAULT))
+                }
+            }
+        })+
+    };
+}
+
+impl_fmt_for_quantity! {
+    Display, "";
+    LowerExp, "e";
+    UpperExp, "E";
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::isq::consts::*;
-    use crate::typenum::Const;
+    use crate::{
+        isq::{consts::*, unit::Meter},
+        Quantity,
+    };
 
     // #[test]
     // fn add_quantity_to_quantity() {
@@ -147,4 +503,120 @@ mod tests {
     //     let time = 3_f32 * s;
     //     assert_eq!(distance / time, 7_f32 * (m / s));
     // }
+
+    #[test]
+    fn scale_quantity_by_value() {
+        let speed = 10_f64 * (m / s);
+        assert_eq!(2.0 * speed, 20_f64 * (m / s));
+        assert_eq!(speed * 2.0, 20_f64 * (m / s));
+        assert_eq!(speed / 4.0, 2.5 * (m / s));
+        assert_eq!(20.0 / speed, 2_f64 * (s / m));
+        assert_eq!(3_u8 * (4_u8 * kg), 12_u8 * kg);
+    }
+
+    #[test]
+    fn assign_ops() {
+        let mut position = 1_f64 * m;
+        let velocity = 2_f64 * (m / s);
+        let dt = 0.5 * s;
+        position += velocity * dt;
+        assert_eq!(position, 2_f64 * m);
+        position -= &(0.5 * m);
+        assert_eq!(position, 1.5 * m);
+        position *= 4.0;
+        assert_eq!(position, 6_f64 * m);
+        position /= 3.0;
+        assert_eq!(position, 2_f64 * m);
+    }
+
+    #[test]
+    fn neg_and_rem() {
+        let force = 3_i32 * (kg * m / (s * s));
+        assert_eq!(-force, -3 * (kg * m / (s * s)));
+        assert_eq!(-&force, -3 * (kg * m / (s * s)));
+        assert_eq!(7.5 * m % (2_f64 * m), 1.5 * m);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn ops_on_references() {
+        let (a, b) = (6_f64 * m, 2_f64 * s);
+        assert_eq!(&a + &a, 12_f64 * m);
+        assert_eq!(a - &a, 0_f64 * m);
+        assert_eq!(&a / b, 3_f64 * (m / s));
+        assert_eq!(&a * &b, 12_f64 * (m * s));
+        assert_eq!(&a % a, 0_f64 * m);
+        assert_eq!(2.0 * &a, &a * 2.0);
+        assert_eq!(&a / 2.0, 3_f64 * m);
+        assert_eq!(12.0 / &b, 6_f64 / s);
+    }
+
+    #[test]
+    fn sum_quantities() {
+        let lengths = vec![1.5 * m, 2_f64 * m, 0.5 * m];
+        assert_eq!(lengths.iter().sum::<Quantity<_, _>>(), 4_f64 * m);
+        assert_eq!(lengths.into_iter().sum::<Quantity<_, _>>(), 4_f64 * m);
+    }
+
+    #[test]
+    fn product_of_quantities() {
+        let sides = [2_i32 * m, 3 * m, 4 * m];
+        assert_eq!(Quantity::product(sides), 24 * (m * m * m));
+        assert_eq!(Quantity::product([2_f64 * m, 0.5 * m]), 1_f64 * (m * m));
+        assert_eq!(Quantity::<Meter, f64>::product([]), 1_f64 * (m / m));
+    }
+
+    #[test]
+    fn powers_of_quantities() {
+        let side = 3_f64 * m;
+        assert_eq!(side.powi::<3>(), 27_f64 * (m * m * m));
+        assert_eq!(side.powi::<-2>(), (1.0 / 9.0) / (m * m));
+        assert_eq!(side.powi::<0>(), 1_f64 * (m / m));
+        assert_eq!((4_f64 * s).recip(), 0.25 / s);
+        assert_eq!((2_i64 * (m / s)).powi::<2>(), 4_i64 * (m * m / (s * s)));
+    }
+
+    #[test]
+    fn roots_of_quantities() {
+        let area = 9_f64 * (m * m);
+        assert_eq!(area.sqrt(), 3_f64 * m);
+        assert_eq!((8_f64 * (m * m * m)).cbrt(), 2_f64 * m);
+        assert_eq!((16_f32 * (m * m / (s * s))).sqrt(), 4_f32 * (m / s));
+
+        let noise = (4_f64 * (m * m / s)).sqrt();
+        assert_eq!(noise.value, 2.0);
+        assert_eq!(noise.to_string(), "2 m/s^½");
+        assert_eq!((noise * noise), 4_f64 * (m * m / s));
+    }
+
+    #[test]
+    fn format_flags() {
+        let speed = 12.3456_f64 * (m / s);
+        assert_eq!(format!("{speed:.2}"), "12.35 m/s");
+        assert_eq!(format!("{speed:+.1}"), "+12.3 m/s");
+        assert_eq!(format!("{speed:>13}"), "  12.3456 m/s");
+        assert_eq!(format!("{speed:*^14.1}"), "***12.3 m/s***");
+        assert_eq!(format!("{speed:<10.0}|"), "12 m/s    |");
+        assert_eq!(format!("{speed:e}"), "1.23456e1 m/s");
+        assert_eq!(format!("{speed:.2E}"), "1.23E1 m/s");
+        assert_eq!(format!("{:+}", -3_i32 * m), "-3 m");
+        assert_eq!(format!("{:+}", 3_i32 * m), "+3 m");
+        assert_eq!(format!("{:#>#14.1}", 2_f64 * N), "#2.0 (m⋅kg)/s²");
+        assert_eq!(format!("{:>9.1}", 20_f64 * degC), "  20.0 °C");
+    }
+
+    #[test]
+    fn debug_quantities() {
+        let distance = 3_f64 * m;
+        let unit = r#"Unit { L: Slot { root: "meter", prefix: None, exp: 1 }, dimension: L¹M⁰T⁰I⁰Θ⁰N⁰J⁰An⁰R⁰Inf⁰ }"#;
+        assert_eq!(format!("{distance:?}"), format!("Quantity {{ value: 3.0, unit: {unit} }}"));
+        assert_eq!(
+            format!("{distance:+.2?}"),
+            format!("Quantity {{ value: +3.00, unit: {unit} }}")
+        );
+        assert_eq!(format!("{:?}", 2_i32 * (m / s)).matches("Slot").count(), 2);
+        assert!(format!("{:#?}", distance)
+            .starts_with("Quantity {\n    value: 3.0,\n    unit: Unit {\n"));
+        assert!(format!("{distance:>200?}").starts_with("   "));
+    }
 }
@@ -48,3 +48,78 @@ macro_rules! roots_with_alias {
 }
 
 pub(crate) use roots_with_alias;
+
+/// Coherent roots are their own conversion target, e. g. `meter` or `second`
+macro_rules! coherent_roots {
+    ($($root:ident),+) => {
+        $(impl<V: $crate::ops::One> $crate::base_unit::Factor<V> for $root {
+            type Coherent = Self;
+
+            fn factor() -> (V, V) {
+                (V::ONE, V::ONE)
+            }
+        })+
+    };
+}
+
+pub(crate) use coherent_roots;
+
+/// Roots which are `num / den` of the coherent one, e. g. `foot` is `381 / 1250` of `meter`
+macro_rules! scaled_roots {
+    ($(($root:ident, $num:literal / $den:literal, $coherent:ident),)+) => {
+        $($crate::root::impl_factor!($root, $num, $den, $coherent, i128, u128);
+        $crate::root::impl_factor!(@float $root, $num, $den, $coherent, f32, f64);)+
+    };
+}
+
+pub(crate) use scaled_roots;
+
+/// Roots which are `num / den * π` of the coherent one, e. g. `degree` is `π / 180` of `radian`.
+/// Only floats can represent such factors. `π` is always the numerator, so that it cancels out
+/// in conversions between such roots, e. g. `degree` -> `arcminute`
+macro_rules! pi_scaled_roots {
+    ($(($root:ident, $num:literal / $den:literal, $coherent:ident),)+) => {
+        $(impl $crate::base_unit::Factor<f32> for $root {
+            type Coherent = $coherent;
+
+            fn factor() -> (f32, f32) {
+                (::core::f32::consts::PI, $den as f32 / $num as f32)
+            }
+        }
+
+        impl $crate::base_unit::Factor<f64> for $root {
+            type Coherent = $coherent;
+
+            fn factor() -> (f64, f64) {
+                (::core::f64::consts::PI, $den as f64 / $num as f64)
+            }
+        })+
+    };
+}
+
+pub(crate) use pi_scaled_roots;
+
+macro_rules! impl_factor {
+    // floats may round the factor, e. g. astronomical unit in `f32`
+    (@float $root:ident, $num:literal, $den:literal, $coherent:ident, $($type:ident),+) => {
+        $(impl $crate::base_unit::Factor<$type> for $root {
+            type Coherent = $coherent;
+
+            fn factor() -> ($type, $type) {
+                ($num as i128 as $type, $den as i128 as $type)
+            }
+        })+
+    };
+    // narrower integers are converted via `i128`, so that factors always fit
+    ($root:ident, $num:literal, $den:literal, $coherent:ident, $($type:ident),+) => {
+        $(impl $crate::base_unit::Factor<$type> for $root {
+            type Coherent = $coherent;
+
+            fn factor() -> ($type, $type) {
+                ($num, $den)
+            }
+        })+
+    };
+}
+
+pub(crate) use impl_factor;
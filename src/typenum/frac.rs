@@ -1,8 +1,58 @@
 //! This module should be upstreamed to <https://lib.rs/typenum>
 
-use crate::typenum::{Lcm, LcmOp};
-use core::ops::{Add, Div, Mul};
-use typenum::{Prod, Quot, Sum};
+use crate::{
+    base_unit::Exponent,
+    typenum::{Const, Constant, Lcm, LcmOp, ToConst, ToTypenum, Typenum},
+};
+use const_default::ConstDefault;
+use core::{
+    marker::PhantomData,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+use typenum::{Bit, Gcd, Gcf, Negate, PInt, Prod, Quot, Sum, UInt, UTerm, Unsigned, B1, P1};
+
+/// Fractional exponent `N / D`, e. g. `Frac<Const<1>, Const<2>>` is `½`
+///
+/// Results of operations are always reduced, so `D` is never `Const<1>`: whole numbers are `Const`
+pub struct Frac<N, D>(PhantomData<(N, D)>);
+
+impl<N, D> Clone for Frac<N, D> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<N, D> Copy for Frac<N, D> {}
+
+impl<N, D> ConstDefault for Frac<N, D> {
+    const DEFAULT: Self = Self(PhantomData);
+}
+
+impl<U, const N: i8, const D: i8> Exponent for (U, Frac<Const<N>, Const<D>>) {
+    const EXP: i8 = N;
+    const DEN: i8 = D;
+}
+
+/// `½`
+pub type Half = Frac<Const<1>, Const<2>>;
+/// `⅓`
+pub type Third = Frac<Const<1>, Const<3>>;
+
+/// Operations on fractions `(N, D)` of `typenum` integers.
+/// Separate from `crate::ops` ones, because those are already implemented for `(Unit, Exponent)` tuples
+pub trait FracAdd<Rhs> {
+    type Output;
+}
+
+/// See [`FracAdd`]
+pub trait FracSub<Rhs> {
+    type Output;
+}
+
+/// See [`FracAdd`]
+pub trait FracMul<Rhs> {
+    type Output;
+}
 
 /// **TLDR;** `Sum<(Nl, Dl), (Nr, Dr)> == (Nl / Dl) + (Nr / Dr)`
 ///
@@ -10,19 +60,49 @@ use typenum::{Prod, Quot, Sum};
 /// * `D` -- denominator
 /// * `l` -- left-hand side
 /// * `r` -- right-hand-side
-impl<Nl, Nr, Dl: Mul<Dr> + LcmOp<Dr>, Dr> crate::ops::Add<(Nr, Dr)> for (Nl, Dl)
+impl<Nl, Nr, Dl: Mul<Dr> + LcmOp<Dr>, Dr> FracAdd<(Nr, Dr)> for (Nl, Dl)
 where
     Lcm<Dl, Dr>: Div<Dl> + Div<Dr>,
     Nl: Mul<Quot<Lcm<Dl, Dr>, Dl>>,
     Nr: Mul<Quot<Lcm<Dl, Dr>, Dr>>,
     Prod<Nl, Quot<Lcm<Dl, Dr>, Dl>>: Add<Prod<Nr, Quot<Lcm<Dl, Dr>, Dr>>>,
-    Sum<Prod<Nl, Quot<Lcm<Dl, Dr>, Dl>>, Prod<Nr, Quot<Lcm<Dl, Dr>, Dr>>>: Div<Lcm<Dl, Dr>>,
+    (Sum<Prod<Nl, Quot<Lcm<Dl, Dr>, Dl>>, Prod<Nr, Quot<Lcm<Dl, Dr>, Dr>>>, Lcm<Dl, Dr>): Reduce,
 {
     /// Sum of 2 fractions
     type Output = <(Nl, Dl) as hidden::FracAddImpl<(Nr, Dr)>>::Output;
 }
 
+/// `(Nl / Dl) - (Nr / Dr) == (Nl / Dl) + (-Nr / Dr)`
+impl<Nl, Nr: Neg, Dl, Dr> FracSub<(Nr, Dr)> for (Nl, Dl)
+where
+    (Nl, Dl): FracAdd<(Negate<Nr>, Dr)>,
+{
+    /// Difference of 2 fractions
+    type Output = <(Nl, Dl) as FracAdd<(Negate<Nr>, Dr)>>::Output;
+}
+
+/// `(Nl / Dl) * (Nr / Dr) == (Nl * Nr) / (Dl * Dr)`
+impl<Nl: Mul<Nr>, Nr, Dl: Mul<Dr>, Dr> FracMul<(Nr, Dr)> for (Nl, Dl)
+where
+    (Prod<Nl, Nr>, Prod<Dl, Dr>): Reduce,
+{
+    /// Product of 2 fractions
+    type Output = Reduced<(Prod<Nl, Nr>, Prod<Dl, Dr>)>;
+}
+
+/// Division of numerator & denominator by their greatest common divisor
+pub trait Reduce {
+    type Output;
+}
+
+pub type Reduced<F> = <F as Reduce>::Output;
+
+impl<N: Gcd<D> + Div<Gcf<N, D>>, D: Div<Gcf<N, D>>> Reduce for (N, D) {
+    type Output = (Quot<N, Gcf<N, D>>, Quot<D, Gcf<N, D>>);
+}
+
 mod hidden {
+    use super::{Reduce, Reduced};
     use crate::typenum::{Lcm, LcmOp};
     use core::ops::{Add, Div, Mul};
     use typenum::{Prod, Quot, Sum};
@@ -42,10 +122,120 @@ mod hidden {
         Nl: Mul<Quot<Lcm<Dl, Dr>, Dl>>,
         Nr: Mul<Quot<Lcm<Dl, Dr>, Dr>>,
         Prod<Nl, Quot<Lcm<Dl, Dr>, Dl>>: Add<Prod<Nr, Quot<Lcm<Dl, Dr>, Dr>>>,
-        Sum<Prod<Nl, Quot<Lcm<Dl, Dr>, Dl>>, Prod<Nr, Quot<Lcm<Dl, Dr>, Dr>>>: Div<Lcm<Dl, Dr>>,
+        (Sum<Prod<Nl, Quot<Lcm<Dl, Dr>, Dl>>, Prod<Nr, Quot<Lcm<Dl, Dr>, Dr>>>, Lcm<Dl, Dr>):
+            Reduce,
     {
         type Lcd = Lcm<Dl, Dr>;
         type NOut = Sum<Prod<Nl, Quot<Self::Lcd, Dl>>, Prod<Nr, Quot<Self::Lcd, Dr>>>;
-        type Output = Quot<Self::NOut, Self::Lcd>;
+        type Output = Reduced<(Self::NOut, Self::Lcd)>;
+    }
+}
+
+/// `Const` or `Frac` as a fraction of `typenum` integers, e. g. `Const<2>` is `(P2, P1)`
+pub trait ToTypenumFrac {
+    type Frac;
+}
+
+pub type TypenumFrac<F> = <F as ToTypenumFrac>::Frac;
+
+impl<const N: i8> ToTypenumFrac for Const<N>
+where
+    Const<N>: ToTypenum,
+{
+    type Frac = (Typenum<Const<N>>, P1);
+}
+
+impl<N: ToTypenum, D: ToTypenum> ToTypenumFrac for Frac<N, D> {
+    type Frac = (Typenum<N>, Typenum<D>);
+}
+
+/// Reduced fraction of `typenum` integers as `Const` (if denominator is 1) or `Frac`
+pub trait ToConstFrac {
+    type ConstFrac;
+}
+
+pub type ConstFrac<F> = <F as ToConstFrac>::ConstFrac;
+
+impl<N, D: Denominator<N>> ToConstFrac for (N, D) {
+    type ConstFrac = D::ConstFrac;
+}
+
+/// Dispatch on the denominator, so that there's no need in an impl per fraction
+pub trait Denominator<N> {
+    type ConstFrac;
+}
+
+/// `N / 1 == N`
+impl<N: ToConst> Denominator<N> for PInt<UInt<UTerm, B1>> {
+    type ConstFrac = Constant<N>;
+}
+
+/// Denominator `>= 2`
+impl<N: ToConst, U: Unsigned, Ba: Bit, Bb: Bit> Denominator<N> for PInt<UInt<UInt<U, Ba>, Bb>>
+where
+    Self: ToConst,
+{
+    type ConstFrac = Frac<Constant<N>, Constant<Self>>;
+}
+
+macro_rules! impl_binary_ops_for_frac {
+    ($([$($generic:tt)+] $lhs:ty, $rhs:ty;)+) => {
+        $(impl_binary_ops_for_frac!(@op [$($generic)+] $lhs, $rhs, Add, add, FracAdd);
+        impl_binary_ops_for_frac!(@op [$($generic)+] $lhs, $rhs, Sub, sub, FracSub);
+        impl_binary_ops_for_frac!(@op [$($generic)+] $lhs, $rhs, Mul, mul, FracMul);)+
+    };
+    (@op [$($generic:tt)+] $lhs:ty, $rhs:ty, $op:ident, $fun:ident, $frac_op:ident) => {
+        impl<$($generic)+> $op<$rhs> for $lhs
+        where
+            $lhs: ToTypenumFrac,
+            $rhs: ToTypenumFrac,
+            TypenumFrac<$lhs>: $frac_op<TypenumFrac<$rhs>>,
+            <TypenumFrac<$lhs> as $frac_op<TypenumFrac<$rhs>>>::Output: ToConstFrac,
+            ConstFrac<<TypenumFrac<$lhs> as $frac_op<TypenumFrac<$rhs>>>::Output>: ConstDefault,
+        {
+            type Output = ConstFrac<<TypenumFrac<$lhs> as $frac_op<TypenumFrac<$rhs>>>::Output>;
+
+            fn $fun(self, _: $rhs) -> Self::Output {
+                Self::Output::DEFAULT
+            }
+        }
+    };
+}
+
+impl_binary_ops_for_frac! {
+    [const L: i8, Nr, Dr] Const<L>, Frac<Nr, Dr>;
+    [Nl, Dl, const R: i8] Frac<Nl, Dl>, Const<R>;
+    [Nl, Dl, Nr, Dr] Frac<Nl, Dl>, Frac<Nr, Dr>;
+}
+
+impl<N: Neg, D> Neg for Frac<N, D> {
+    type Output = Frac<Negate<N>, D>;
+
+    fn neg(self) -> Self::Output {
+        Frac(PhantomData)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Frac, Half, Third};
+    use crate::Const;
+    use core::any::TypeId;
+    use typenum::{Diff, Negate, Prod, Sum};
+
+    fn same<L: 'static, R: 'static>() -> bool {
+        TypeId::of::<L>() == TypeId::of::<R>()
+    }
+
+    #[test]
+    fn frac_ops() {
+        assert!(same::<Sum<Half, Half>, Const<1>>());
+        assert!(same::<Sum<Half, Third>, Frac<Const<5>, Const<6>>>());
+        assert!(same::<Diff<Const<1>, Half>, Half>());
+        assert!(same::<Diff<Third, Half>, Frac<Const<-1>, Const<6>>>());
+        assert!(same::<Prod<Const<2>, Half>, Const<1>>());
+        assert!(same::<Prod<Const<0>, Third>, Const<0>>());
+        assert!(same::<Prod<Frac<Const<3>, Const<4>>, Frac<Const<2>, Const<3>>>, Half>());
+        assert!(same::<Negate<Half>, Frac<Const<-1>, Const<2>>>());
     }
 }
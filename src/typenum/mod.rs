@@ -6,10 +6,7 @@ use crate::util::{binary_ops_out_aliases, trait_alias};
 use const_default::ConstDefault;
 use core::ops::{Add, Div, Mul, Sub};
 use std::ops::Neg;
-use typenum::{
-    op, Abs, Diff, Gcd, Gcf, Integer, Negate, NonZero, Prod, Quot, Sum, Unsigned, N1, N2, N3, N4,
-    N5, N6, N7, N8, P1, P2, P3, P4, P5, P6, P7, P8, Z0,
-};
+use typenum::{op, Abs, Diff, Gcd, Gcf, Integer, Negate, NonZero, Prod, Quot, Sum, Unsigned, Z0};
 
 trait_alias!((Unsigned, NonZero) -> Positive);
 
@@ -49,40 +46,71 @@ impl<const N: i8> ConstDefault for Const<N> {
     const DEFAULT: Self = Self;
 }
 
+/// Number of factors, e. g. of `Quantity::product`, which is mapped to the exponent `Const<N>`
+pub struct Count<const N: usize>;
+
+/// Maps `Const<±n>` to `typenum`'s `Pn`/`Nn` & back, & `Count<n>` to `Const<n>`,
+/// for each listed magnitude `n`
 macro_rules! num_to_typenum_and_back {
-    ($($const:literal <-> $typenum:ident,)+) => {
-        $(impl ToTypenum for Const<$const> {
-            type Typenum = $typenum;
-        }
+    ($($num:literal)+) => {
+        paste::paste! {
+            $(impl ToTypenum for Const<$num> {
+                type Typenum = typenum::[<P $num>];
+            }
 
-        impl ToConst for $typenum {
-            type Const = Const<$const>;
-        }
+            impl ToConst for typenum::[<P $num>] {
+                type Const = Const<$num>;
+            }
 
-        impl<U> Exponent for (U, Const<$const>) {
-            const EXP: i8 = $const;
-        })+
+            impl ToConst for Count<$num> {
+                type Const = Const<$num>;
+            }
+
+            impl ToTypenum for Const<{ -$num }> {
+                type Typenum = typenum::[<N $num>];
+            }
+
+            impl ToConst for typenum::[<N $num>] {
+                type Const = Const<{ -$num }>;
+            })+
+        }
     };
 }
 
+impl ToTypenum for Const<0> {
+    type Typenum = Z0;
+}
+
+impl ToConst for Z0 {
+    type Const = Const<0>;
+}
+
+impl ToConst for Count<0> {
+    type Const = Const<0>;
+}
+
+impl ToTypenum for Const<{ i8::MIN }> {
+    type Typenum = typenum::N128;
+}
+
+impl ToConst for typenum::N128 {
+    type Const = Const<{ i8::MIN }>;
+}
+
+impl<U, const E: i8> Exponent for (U, Const<E>) {
+    const EXP: i8 = E;
+}
+
+// Whole `i8` range
 num_to_typenum_and_back! {
-   -8 <-> N8,
-   -7 <-> N7,
-   -6 <-> N6,
-   -5 <-> N5,
-   -4 <-> N4,
-   -3 <-> N3,
-   -2 <-> N2,
-   -1 <-> N1,
-    0 <-> Z0,
-    1 <-> P1,
-    2 <-> P2,
-    3 <-> P3,
-    4 <-> P4,
-    5 <-> P5,
-    6 <-> P6,
-    7 <-> P7,
-    8 <-> P8,
+    1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16
+    17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32
+    33 34 35 36 37 38 39 40 41 42 43 44 45 46 47 48
+    49 50 51 52 53 54 55 56 57 58 59 60 61 62 63 64
+    65 66 67 68 69 70 71 72 73 74 75 76 77 78 79 80
+    81 82 83 84 85 86 87 88 89 90 91 92 93 94 95 96
+    97 98 99 100 101 102 103 104 105 106 107 108 109 110 111 112
+    113 114 115 116 117 118 119 120 121 122 123 124 125 126 127
 }
 
 macro_rules! impl_binary_ops_for_num {
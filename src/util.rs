@@ -17,6 +17,12 @@ macro_rules! type_array {
     ($name:ident<$($param:ident = $default:ident),+>) => {
         pub struct $name<$($param = ($default, crate::typenum::Const<0>)),+>(::core::marker::PhantomData<($($param),+)>);
 
+        impl<$($param),+> Default for $name<$($param),+> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
         impl<$($param),+> $name<$($param),+> {
             const LEN: usize = $crate::util::count_idents!($($param),+);
 
@@ -52,11 +58,31 @@ macro_rules! impl_unary_op_for_type_array {
 
 pub(crate) use impl_unary_op_for_type_array;
 
+/// Implement unary operator with a parameter, e. g. `Pow<Const<3>>`.
+/// Elements with zero exponent are normalized to their defaults, e. g. `km.pow::<0>()` is `m⁰`
+macro_rules! impl_unary_op_with_param_for_type_array {
+    ($name:ident<$($type_param:ident = $default:ty),+>, $op:ident<$param:ident>, $op_bound:ident) => {
+        impl<$param, $($type_param: $op_bound<$param>,)+> $op<$param> for $name<$($type_param),+>
+        where
+            $(<$type_param as $op_bound<$param>>::Output: $crate::base_unit::Normalize<$default>,)+
+        {
+            type Output = $name<$($crate::base_unit::Normalized<<$type_param as $op_bound<$param>>::Output, $default>,)+>;
+        }
+    };
+}
+
+pub(crate) use impl_unary_op_with_param_for_type_array;
+
+/// Implement binary operator, e. g. `Mul`.
+/// Elements with zero exponent are normalized to their defaults, e. g. `km / m` is `m⁰`
 macro_rules! impl_binary_op_for_type_array {
-    ($name:ident<$($param:ident),+>, $op:ident, $op_bound:ident) => {
+    ($name:ident<$($param:ident = $default:ty),+>, $op:ident, $op_bound:ident) => {
         paste::paste! {
-            impl<$( [<$param 1>] : $op_bound< [<$param 2>] >,)+ $( [<$param 2>] ),+> $op<$name<$( [<$param 2>] ),+>> for $name<$( [<$param 1>] ),+> {
-                type Output = $name<$(<[<$param 1>] as $op_bound<[<$param 2>]>>::Output,)+>;
+            impl<$( [<$param 1>] : $op_bound< [<$param 2>] >,)+ $( [<$param 2>] ),+> $op<$name<$( [<$param 2>] ),+>> for $name<$( [<$param 1>] ),+>
+            where
+                $(<[<$param 1>] as $op_bound<[<$param 2>]>>::Output: $crate::base_unit::Normalize<$default>,)+
+            {
+                type Output = $name<$($crate::base_unit::Normalized<<[<$param 1>] as $op_bound<[<$param 2>]>>::Output, $default>,)+>;
 
                 fn [<$op:lower>](self, _: $name<$( [<$param 2>] ),+>) -> Self::Output {
                     Self::Output::new()
@@ -68,6 +94,74 @@ macro_rules! impl_binary_op_for_type_array {
 
 pub(crate) use impl_binary_op_for_type_array;
 
+/// Implement binary operator, which works on types only, e. g. `Align`
+macro_rules! impl_binary_type_op_for_type_array {
+    ($name:ident<$($param:ident),+>, $op:ident, $op_bound:ident) => {
+        paste::paste! {
+            impl<$( [<$param 1>] : $op_bound< [<$param 2>] >,)+ $( [<$param 2>] ),+> $op<$name<$( [<$param 2>] ),+>> for $name<$( [<$param 1>] ),+> {
+                type Output = $name<$(<[<$param 1>] as $op_bound<[<$param 2>]>>::Output,)+>;
+            }
+        }
+    };
+}
+
+pub(crate) use impl_binary_type_op_for_type_array;
+
+/// Implement `ConvertFrom` for type array by converting each of its elements
+macro_rules! impl_convert_from_for_type_array {
+    ($name:ident<$($param:ident),+>) => {
+        paste::paste! {
+            impl<$( [<$param 1>], )+ $( [<$param 2>]: $crate::base_unit::ConvertFrom<[<$param 1>], V>, )+ V>
+                $crate::base_unit::ConvertFrom<$name<$( [<$param 1>] ),+>, V> for $name<$( [<$param 2>] ),+>
+            {
+                fn convert_from(value: V) -> V {
+                    $(let value = [<$param 2>]::convert_from(value);)+
+                    value
+                }
+
+                fn checked_convert_from(value: V) -> Option<V> {
+                    $(let value = [<$param 2>]::checked_convert_from(value)?;)+
+                    Some(value)
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use impl_convert_from_for_type_array;
+
+/// Nested pairs `(A, (B, (C, ())))` of types, e. g. for `AnyBit`
+macro_rules! nested {
+    () => { () };
+    ($head:ty $(, $tail:ty)*) => { ($head, $crate::util::nested!($($tail),*)) };
+}
+
+pub(crate) use nested;
+
+/// Implement `AlignValue` for type array by aligning each of its elements
+macro_rules! impl_align_value_for_type_array {
+    ($name:ident<$($param:ident),+>) => {
+        paste::paste! {
+            impl<$( [<$param 1>]: $crate::base_unit::AlignValue<[<$param 2>], V>, )+ $( [<$param 2>], )+ V>
+                $crate::base_unit::AlignValue<$name<$( [<$param 2>] ),+>, V> for $name<$( [<$param 1>] ),+>
+            where
+                $crate::util::nested!($( <[<$param 1>] as $crate::base_unit::AlignValue<[<$param 2>], V>>::Changed ),+):
+                    $crate::base_unit::AnyBit,
+            {
+                type Changed = <$crate::util::nested!($( <[<$param 1>] as $crate::base_unit::AlignValue<[<$param 2>], V>>::Changed ),+)
+                    as $crate::base_unit::AnyBit>::Output;
+
+                fn align_value(value: V) -> V {
+                    $(let value = [<$param 1>]::align_value(value);)+
+                    value
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use impl_align_value_for_type_array;
+
 /// Generate aliases for unary operators' output type
 /// E. g. `type NegOut<L> = <L as Neg>::Output`
 macro_rules! unary_ops_out_aliases {
@@ -103,10 +197,51 @@ macro_rules! trait_alias {
 
 pub(crate) use trait_alias;
 
-use crate::{
-    base_unit::Pre,
-    isq::{prefix::kilo, root::gram},
-};
+/// Like `Formatter::pad`, but without truncation to precision, which is applied to the value
+pub(crate) fn pad(f: &mut core::fmt::Formatter<'_>, s: &str) -> core::fmt::Result {
+    use core::fmt::{Alignment, Write};
+
+    let padding = f.width().unwrap_or(0).saturating_sub(s.chars().count());
+    let (before, after) = match f.align() {
+        None | Some(Alignment::Left) => (0, padding),
+        Some(Alignment::Right) => (padding, 0),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    f.write_str(s)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+/// `value unit`, or just the value, if the unit is empty, e. g. dimensionless `1.5`
+pub(crate) fn with_unit(value: &str, unit: impl core::fmt::Display) -> String {
+    let unit = unit.to_string();
+    if unit.is_empty() {
+        value.to_string()
+    } else {
+        format!("{value} {unit}")
+    }
+}
+
+/// Formats a value with sign & precision flags of a formatter, e. g. `{:+.2}`,
+/// while its width is left to `pad`. `$spec` is the formatting trait, e. g. `"?"` or `"e"`
+macro_rules! format_value {
+    ($f:expr, $value:expr, $spec:literal) => {
+        match ($f.sign_plus(), $f.precision()) {
+            (false, None) => format!(concat!("{:", $spec, "}"), $value),
+            (false, Some(precision)) => format!(concat!("{:.*", $spec, "}"), precision, $value),
+            (true, None) => format!(concat!("{:+", $spec, "}"), $value),
+            (true, Some(precision)) => format!(concat!("{:+.*", $spec, "}"), precision, $value),
+        }
+    };
+}
+
+pub(crate) use format_value;
 
 #[cfg(test)]
 mod tests {